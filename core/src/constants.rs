@@ -0,0 +1,141 @@
+// while redundant this file exists as a possible user-reference to discover all possible properties
+// available to them for a certain element
+
+
+// error messages
+pub const CONFIG_PROP_ERR_MSG: &str =
+    "Unexpected property: \"{prop}\" (must be one of the following: {values})";
+pub const SUGGESTION_MSG: &str =
+    " (did you mean \"{suggestion}\"?)";
+pub const DEFAULT_CTOR_ERR_MSG: &str =
+    "Default constructor requires field to generate its own value.";
+pub const DUPLICATE_CTOR_ERR_MSG: &str =
+    "Duplicate constructor: \"{name}\"";
+pub const MULTIPLE_DEFAULT_ERR_MSG: &str =
+    "Only one constructor can implement `Default`";
+pub const MULTIPLE_PROP_ERR_MSG: &str =
+    "Only one property can be specified per field";
+pub const UNUSED_SELF_EXPR_ERR_MSG: &str =
+    "expr! adds \"{field}\" as a parameter, but the expression never references it (use expr(...) instead)";
+pub const CTOR_INDEX_ERR_MSG: &str =
+    "Constructor index {index} is out of range (only {count} constructor(s) are defined)";
+pub const CTOR_NAME_ERR_MSG: &str =
+    "Unknown constructor: \"{name}\"";
+
+pub const DUPLICATE_PARAM_ERR_MSG: &str =
+    "Duplicate constructor parameter: \"{param}\"";
+pub const UNUSED_PARAM_ERR_MSG: &str =
+    "Unused constructor parameter: \"{param}\" (it must be referenced by a field expression)";
+pub const ORDER_PARAM_ERR_MSG: &str =
+    "Parameter position {order} is out of range or already taken";
+pub const UNKNOWN_PARAM_ERR_MSG: &str =
+    "Unknown constructor parameter: \"{param}\"";
+pub const SPECIALIZATION_ERR_MSG: &str =
+    "Specialized constructor must provide an argument for every generic parameter (expected {expected}, found {found})";
+pub const GENERIC_DEFAULT_ERR_MSG: &str =
+    "Default constructor cannot declare generic parameters";
+pub const SPECIALIZED_ASSOC_ERR_MSG: &str =
+    "Unable to resolve the trait of \"{type}\" within a specialized constructor, as \"{param}\" does not have exactly one trait bound (use a qualified path instead, ex: <{param} as Trait>::Item)";
+pub const SPECIALIZED_DEFAULT_ERR_MSG: &str =
+    "Default constructor cannot be specialized to concrete generic arguments";
+pub const ITER_INFER_ERR_MSG: &str =
+    "Unable to infer the item type of \"{type}\", use the explicit form instead: iter(TYPE)";
+pub const FALLIBLE_ERROR_TYPE_ERR_MSG: &str =
+    "Constructor with multiple fallible fields must declare its error type, ex: new(error = MyError)";
+pub const FALLIBLE_DEFAULT_ERR_MSG: &str =
+    "Default constructor cannot be fallible";
+pub const CONST_FIELD_ERR_MSG: &str =
+    "Field \"{field}\" cannot be generated within a const constructor: {reason}";
+pub const CONST_DEFAULT_ERR_MSG: &str =
+    "Default constructor cannot be const, as it is generated as an implementation of `Default`";
+pub const ITER_SCALAR_ERR_MSG: &str =
+    "\"iter\" requires a collection field, but \"{type}\" is not a collection (did you mean \"into\"?)";
+pub const EXPR_RAW_ERR_MSG: &str =
+    "raw!({field}) requires \"{field}\" to be a parameter of the constructor";
+
+pub const METADATA_ERR_MSG: &str =
+    "Invalid value for \"{key}\" in the derive-ctor metadata of Cargo.toml (expected {expected})";
+
+pub const DEBUG_EXPANSION_MSG: &str =
+    "Expansion of #[derive(ctor)] for \"{type}\":\n\n{expansion}";
+
+pub const FOREIGN_ATTRIBUTE_WARN_MSG: &str =
+    "{attr} is not a ctor attribute and does not configure the constructor, use #[ctor({prop})] instead";
+
+pub const CTOR_WORD: &str = "ctor";
+pub const CFG_WORD: &str = "cfg";
+
+// valid field properties
+pub const FIELD_PROP_CLONED: &str = "cloned";
+pub const FIELD_PROP_DEFAULT: &str = "default";
+pub const FIELD_PROP_EXPR: &str = "expr";
+pub const FIELD_PROP_INTO: &str = "into";
+pub const FIELD_PROP_ITER: &str = "iter";
+pub const FIELD_PROP_REPEAT: &str = "repeat";
+pub const FIELD_PROP_FROM_FN: &str = "from_fn";
+pub const FIELD_PROP_MARKER: &str = "marker";
+
+// valid field options, specified alongside a property ex: #[ctor(into, name = value)]
+pub const FIELD_PROP_NAME: &str = "name";
+pub const FIELD_PROP_ORDER: &str = "order";
+
+// marker used within field expressions to reference a parameter before it is converted
+pub const EXPR_RAW_MARKER: &str = "raw";
+
+// conversions applied to each item of an iter property, ex: #[ctor(iter(into String))]
+pub const ITER_CONVERSION_INTO: &str = "into";
+pub const ITER_CONVERSION_TRY_INTO: &str = "try_into";
+
+// valid enum-config properties
+pub const ENUM_PROP_PREFIX: &str = "prefix";
+pub const ENUM_PROP_VISIBILITY: &str = "visibility";
+pub const ENUM_PROP_VIS: &str = "vis";
+pub const ENUM_PROP_DEFAULT: &str = "default";
+pub const ENUM_PROP_MARKERS: &str = "markers";
+pub const ENUM_PROP_NAMING: &str = "naming";
+pub const ENUM_PROP_DEBUG: &str = "debug";
+// values of the default = VALUE property
+pub const ENUM_DEFAULT_VALUE_STD: &str = "std";
+
+// value of the vis = VALUE property which matches the visibility of the type itself
+pub const VISIBILITY_VALUE_INHERIT: &str = "inherit";
+
+// variation property
+pub const ENUM_VARIATION_PROP_NONE: &str = "none";
+
+// struct config properties
+pub const STRUCT_PROP_INTO: &str = "into";
+pub const STRUCT_PROP_DEFAULT: &str = "default";
+pub const STRUCT_PROP_ORDER: &str = "order";
+pub const STRUCT_PROP_GENERICS: &str = "generics";
+pub const STRUCT_PROP_ERROR: &str = "error";
+pub const STRUCT_PROP_VISIBILITY: &str = "visibility";
+pub const STRUCT_PROP_VIS: &str = "vis";
+// declares the zero-sized types which are generated instead of being passed as parameters, ex: markers(Tag<_>)
+pub const STRUCT_PROP_MARKERS: &str = "markers";
+// reports the generated code as a compile error, ex: #[ctor(debug = true)]
+pub const STRUCT_PROP_DEBUG: &str = "debug";
+// values of the generics = VALUE property
+pub const GENERICS_VALUE_NAMED: &str = "named";
+pub const GENERICS_VALUE_IMPL: &str = "impl";
+// property used within the default() prop
+pub const NESTED_PROP_ALL: &str = "all";
+
+// package-wide defaults read from Cargo.toml, ex: [package.metadata.derive-ctor]
+pub const METADATA_PACKAGE_TABLE: &str = "package.metadata.derive-ctor";
+pub const METADATA_WORKSPACE_TABLE: &str = "workspace.metadata.derive-ctor";
+pub const METADATA_VIS: &str = "vis";
+pub const METADATA_NAME: &str = "name";
+pub const METADATA_PREFIX: &str = "prefix";
+pub const METADATA_NAMING: &str = "naming";
+pub const METADATA_ATTRIBUTES: &str = "attributes";
+pub const METADATA_SHORTHAND_WARNINGS: &str = "shorthand-warnings";
+pub const METADATA_RUNTIME: &str = "runtime";
+// values of the naming = VALUE metadata and enum property
+pub const NAMING_VALUE_SNAKE_CASE: &str = "snake_case";
+pub const NAMING_VALUE_PRESERVE: &str = "preserve";
+
+// environment variable listing the types whose generated code is dumped during the build, ex: DERIVE_CTOR_DEBUG=MyStruct
+pub const DEBUG_ENV_VAR: &str = "DERIVE_CTOR_DEBUG";
+pub const DEBUG_ENV_VALUE_ALL: &str = "*";
+pub const DEBUG_OUT_DIR: &str = "derive-ctor";
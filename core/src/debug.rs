@@ -0,0 +1,249 @@
+extern crate alloc;
+extern crate std;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use std::path::PathBuf;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use syn::{Error, Ident};
+
+use crate::constants::{DEBUG_ENV_VALUE_ALL, DEBUG_ENV_VAR, DEBUG_EXPANSION_MSG, DEBUG_OUT_DIR};
+
+/// Keywords which are followed by a space even if the next token is a path separator, ex: `impl ::core::clone::Clone`
+const KEYWORDS: &[&str] = &["impl", "for", "as", "dyn", "where", "return", "in", "mut", "let", "else", "move", "ref"];
+/// Operators which are never followed by a space, ex: `&self` or `#[inline]`
+const GLUED_OPERATORS: &[&str] = &["::", ".", "#", "&", "'", "<", "!"];
+/// Operators which are followed by a path separator with a space, ex: `-> ::core::result::Result`
+const SPACED_OPERATORS: &[&str] = &["=", "->", "=>", ",", ":", "+"];
+
+/// Reports the generated code of a type if it is configured with `#[ctor(debug = true)]` (as a compile error pointing at
+/// the type) or listed by the `DERIVE_CTOR_DEBUG` environment variable (written to `OUT_DIR/derive-ctor/TYPE.rs` if the
+/// package has a build script, and to stderr otherwise). The generated code itself is always kept.
+pub(crate) fn debug_expansion(ident: &Ident, is_debug: bool, expansion: TokenStream) -> TokenStream {
+    let is_listed = std::env::var(DEBUG_ENV_VAR).is_ok_and(|types| is_listed(&types, &ident.to_string()));
+    if !is_debug && !is_listed {
+        return expansion;
+    }
+
+    let pretty = pretty_print(expansion.clone());
+    if is_listed {
+        write_expansion(ident, &pretty);
+    }
+    if !is_debug {
+        return expansion;
+    }
+
+    let message = DEBUG_EXPANSION_MSG.replace("{type}", &ident.to_string()).replace("{expansion}", &pretty);
+    let mut output = expansion;
+    output.extend(Error::new(ident.span(), message).into_compile_error());
+    output
+}
+
+/// Whether the type is named within the comma-separated list of the environment variable, where `*` matches every type
+fn is_listed(types: &str, ident: &str) -> bool {
+    types.split(',').map(str::trim).any(|name| name == ident || name == DEBUG_ENV_VALUE_ALL)
+}
+
+fn write_expansion(ident: &Ident, pretty: &str) {
+    let written = std::env::var_os("OUT_DIR").map(|out_dir| {
+        let directory = PathBuf::from(out_dir).join(DEBUG_OUT_DIR);
+        std::fs::create_dir_all(&directory)
+            .and_then(|_| std::fs::write(directory.join(format!("{}.rs", ident)), pretty))
+            .is_ok()
+    });
+    if written != Some(true) {
+        std::eprintln!("// {}\n{}", ident, pretty);
+    }
+}
+
+/// Formats generated code to be readable, breaking lines after statements, attributes and braces. The output is
+/// not meant to match rustfmt, only to be read.
+pub fn pretty_print(tokens: TokenStream) -> String {
+    let mut printer = Printer::default();
+    printer.print_stream(tokens);
+    printer.output.trim_end().to_string()
+}
+
+/// The previously printed token, which determines whether the next one is preceded by a space
+enum Previous {
+    LineStart,
+    Open,
+    Close,
+    Word { is_keyword: bool },
+    Operator(String),
+}
+
+struct Printer {
+    output: String,
+    indent: usize,
+    previous: Previous,
+    pending_newline: bool,
+    /// whether the tokens are within parentheses or brackets, where statements are not broken into lines
+    is_inline: bool,
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self { output: String::new(), indent: 0, previous: Previous::LineStart, pending_newline: false, is_inline: false }
+    }
+}
+
+impl Printer {
+    fn print_stream(&mut self, tokens: TokenStream) {
+        let mut operator = String::new();
+        for token in tokens {
+            if let TokenTree::Punct(punct) = &token {
+                operator.push(punct.as_char());
+                if punct.spacing() == proc_macro2::Spacing::Alone {
+                    self.print_operator(&core::mem::take(&mut operator));
+                }
+                continue;
+            }
+            // a joint punct followed by a non-punct, ex: the quote of a lifetime
+            if !operator.is_empty() {
+                self.print_operator(&core::mem::take(&mut operator));
+            }
+            match token {
+                TokenTree::Group(group) => self.print_group(group.delimiter(), group.stream()),
+                TokenTree::Ident(ident) => {
+                    let word = ident.to_string();
+                    self.print_word(&word, KEYWORDS.contains(&word.as_str()));
+                }
+                TokenTree::Literal(literal) => self.print_word(&literal.to_string(), false),
+                TokenTree::Punct(_) => unreachable!(),
+            }
+        }
+        if !operator.is_empty() {
+            self.print_operator(&operator);
+        }
+    }
+
+    fn print_group(&mut self, delimiter: Delimiter, stream: TokenStream) {
+        let (open, close) = match delimiter {
+            Delimiter::Parenthesis => ("(", ")"),
+            Delimiter::Bracket => ("[", "]"),
+            Delimiter::Brace => ("{", "}"),
+            Delimiter::None => return self.print_stream(stream),
+        };
+        let is_attribute = delimiter == Delimiter::Bracket && matches!(&self.previous, Previous::Operator(op) if op == "#");
+
+        if delimiter == Delimiter::Brace {
+            let space = !matches!(self.previous, Previous::LineStart | Previous::Open);
+            self.write(open, space);
+            if stream.is_empty() {
+                self.write(close, false);
+                self.previous = Previous::Close;
+                self.pending_newline = true;
+                return;
+            }
+            self.indent += 1;
+            self.previous = Previous::LineStart;
+            self.pending_newline = true;
+            let is_inline = core::mem::replace(&mut self.is_inline, false);
+            self.print_stream(stream);
+            self.is_inline = is_inline;
+            self.indent -= 1;
+            self.pending_newline = true;
+            self.write(close, false);
+            self.previous = Previous::Close;
+            self.pending_newline = true;
+            return;
+        }
+
+        let space = match &self.previous {
+            Previous::LineStart | Previous::Open | Previous::Close | Previous::Word { is_keyword: false } => false,
+            Previous::Operator(op) => !GLUED_OPERATORS.contains(&op.as_str()),
+            Previous::Word { is_keyword: true } => true,
+        };
+        self.write(open, space);
+        self.previous = Previous::Open;
+        let is_inline = core::mem::replace(&mut self.is_inline, true);
+        self.print_stream(stream);
+        self.is_inline = is_inline;
+        self.write(close, false);
+        self.previous = Previous::Close;
+        if is_attribute {
+            self.pending_newline = true;
+            self.previous = Previous::LineStart;
+        }
+    }
+
+    fn print_word(&mut self, word: &str, is_keyword: bool) {
+        let space = match &self.previous {
+            Previous::LineStart | Previous::Open => false,
+            Previous::Operator(op) => !GLUED_OPERATORS.contains(&op.as_str()),
+            Previous::Close | Previous::Word { .. } => true,
+        };
+        self.write(word, space);
+        self.previous = Previous::Word { is_keyword };
+    }
+
+    fn print_operator(&mut self, operator: &str) {
+        let is_angle = operator.chars().all(|c| c == '<' || c == '>');
+        let space = match (&self.previous, operator) {
+            (Previous::LineStart | Previous::Open, _) => false,
+            (_, "," | ";" | "." | "?" | ":") => false,
+            (Previous::Operator(op), _) if is_angle => SPACED_OPERATORS.contains(&op.as_str()),
+            (_, _) if is_angle => false,
+            (Previous::Word { is_keyword }, "::") => *is_keyword,
+            (Previous::Operator(op), "::") => SPACED_OPERATORS.contains(&op.as_str()),
+            (_, "::") => false,
+            (Previous::Word { .. }, "!") => false,
+            (Previous::Operator(op), _) => !GLUED_OPERATORS.contains(&op.as_str()),
+            (_, _) => true,
+        };
+        self.write(operator, space);
+        self.previous = Previous::Operator(operator.to_string());
+        if operator == ";" && !self.is_inline {
+            self.pending_newline = true;
+            self.previous = Previous::LineStart;
+        }
+    }
+
+    fn write(&mut self, text: &str, space: bool) {
+        // punctuation and closing delimiters following a brace stay on its line, ex: `});`
+        if self.pending_newline && !matches!(text, ";" | "," | "." | "?" | ")" | "]") {
+            self.output.push('\n');
+            self.output.push_str(&"    ".repeat(self.indent));
+        } else if space && !self.pending_newline {
+            self.output.push(' ');
+        }
+        self.pending_newline = false;
+        self.output.push_str(text);
+    }
+}
+
+#[test]
+fn test_is_listed() {
+    assert!(is_listed("MyStruct", "MyStruct"));
+    assert!(is_listed("Other, MyStruct", "MyStruct"));
+    assert!(is_listed("*", "MyStruct"));
+    assert!(!is_listed("MyStructs", "MyStruct"));
+    assert!(!is_listed("", "MyStruct"));
+}
+
+#[test]
+fn test_pretty_print() {
+    let tokens = quote::quote! {
+        #[automatically_derived]
+        impl<T: ::core::clone::Clone> MyStruct<T> {
+            pub const fn new(value: T, items: &[u8]) -> ::core::result::Result<Self, ()> {
+                let array = [0; 3];
+                ::core::result::Result::Ok(Self { value, items: ::core::convert::Into::into(array) })
+            }
+        }
+    };
+    let expected = [
+        "#[automatically_derived]",
+        "impl<T: ::core::clone::Clone> MyStruct<T> {",
+        "    pub const fn new(value: T, items: &[u8]) -> ::core::result::Result<Self, ()> {",
+        "        let array = [0; 3];",
+        "        ::core::result::Result::Ok(Self {",
+        "            value, items: ::core::convert::Into::into(array)",
+        "        })",
+        "    }",
+        "}",
+    ].join("\n");
+    assert_eq!(expected, pretty_print(tokens));
+}
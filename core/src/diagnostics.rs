@@ -0,0 +1,102 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use proc_macro2::Span;
+use syn::Error;
+
+use crate::constants::{CONFIG_PROP_ERR_MSG, SUGGESTION_MSG};
+
+/// Accumulates errors so that every problem within a derive is reported at once, rather than only the first.
+/// Identical errors (ex: a field error found once per constructor) are only reported once.
+#[derive(Default)]
+pub(crate) struct Diagnostics {
+    errors: Vec<Error>,
+}
+
+impl Diagnostics {
+    pub(crate) fn push(&mut self, error: Error) {
+        for error in error {
+            let is_duplicate = self.errors.iter().any(|existing| {
+                existing.to_string() == error.to_string()
+                    && format!("{:?}", existing.span()) == format!("{:?}", error.span())
+            });
+            if !is_duplicate {
+                self.errors.push(error);
+            }
+        }
+    }
+
+    /// Records the error of the result, returning its value if there was none
+    pub(crate) fn check<T>(&mut self, result: Result<T, Error>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Combines all accumulated errors into a single error
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        self.errors.into_iter().reduce(|mut combined, error| {
+            combined.combine(error);
+            combined
+        }).map_or(Ok(()), Err)
+    }
+}
+
+/// Creates an error for an unexpected property which lists every valid value, suggesting the closest one if the
+/// property looks like a misspelling, ex: `clond` -> `cloned`
+pub(crate) fn unknown_property_error(span: Span, property: &str, values: &[&str]) -> Error {
+    let listed = values.iter().map(|value| format!("\"{}\"", value)).collect::<Vec<String>>().join(", ");
+    let message = CONFIG_PROP_ERR_MSG.replace("{prop}", property).replace("{values}", &listed);
+    Error::new(span, message + &suggestion(property, values))
+}
+
+/// Creates a suffix for an error message which suggests the closest candidate to the value, if any are close enough
+pub(crate) fn suggestion(value: &str, candidates: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+    // allow roughly one edit for every three characters
+    let max_distance = (value.chars().count() / 3).max(1);
+    candidates.into_iter()
+        .map(|candidate| (edit_distance(value, candidate.as_ref()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map_or_else(String::new, |(_, candidate)| SUGGESTION_MSG.replace("{suggestion}", candidate.as_ref()))
+}
+
+/// Calculates the Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[test]
+fn test_edit_distance() {
+    assert_eq!(0, edit_distance("cloned", "cloned"));
+    assert_eq!(1, edit_distance("clond", "cloned"));
+    assert_eq!(2, edit_distance("inot", "into"));
+    assert_eq!(6, edit_distance("", "cloned"));
+}
+
+#[test]
+fn test_suggestion() {
+    let properties = ["cloned", "default", "expr", "into", "iter"];
+    assert_eq!(" (did you mean \"cloned\"?)", suggestion("clond", properties));
+    assert_eq!(" (did you mean \"default\"?)", suggestion("defualt", properties));
+    assert_eq!("", suggestion("serde", properties));
+}
@@ -0,0 +1,434 @@
+use proc_macro2::TokenStream;
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use heck::ToSnakeCase;
+
+use crate::constants::{SPECIALIZED_DEFAULT_ERR_MSG, ENUM_PROP_VIS as VIS, ENUM_PROP_VISIBILITY as VISIBILITY, ENUM_PROP_PREFIX as PREFIX, ENUM_PROP_DEFAULT as DEFAULT, ENUM_DEFAULT_VALUE_STD as STD, ENUM_PROP_MARKERS as MARKERS, ENUM_PROP_NAMING as NAMING, ENUM_PROP_DEBUG as DEBUG, NAMING_VALUE_PRESERVE as PRESERVE, NAMING_VALUE_SNAKE_CASE as SNAKE_CASE};
+use crate::structs::CtorStructConfiguration;
+use crate::{adjust_keyword_ident, cfg_attributes, consume_delimited, CtorAttribute, CtorDefinition, DefaultVisibility, generated_impl_attributes, try_parse_attributes_with_default};
+use crate::debug::debug_expansion;
+use crate::diagnostics::{unknown_property_error, Diagnostics};
+use crate::fields::generate_ctor_meta;
+use crate::metadata::{Metadata, NamingStrategy};
+use crate::validation::Validator;
+
+use proc_macro2::{Delimiter, Span};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::{Comma, Pub};
+use syn::{token, Data, DeriveInput, Error, Fields, Generics, Ident, LitBool, Meta, Type, Variant, Visibility};
+
+const ENUM_CTOR_PROPS: &[&str] = &[PREFIX, VISIBILITY, VIS, DEFAULT, MARKERS, NAMING, DEBUG];
+const ENUM_DEFAULT_VALUES: &[&str] = &[STD];
+const NAMING_VALUES: &[&str] = &[SNAKE_CASE, PRESERVE];
+
+enum EnumConfigItem {
+    Visibility { visibility: DefaultVisibility },
+    Prefix { prefix: Ident },
+    StdDefault,
+    Markers { markers: Vec<Type> },
+    Naming { naming: NamingStrategy },
+    Debug { debug: bool },
+}
+
+/// The configuration of an enum. Properties which are not declared are filled by the metadata of the package.
+#[derive(Default)]
+pub struct CtorEnumConfiguration {
+    pub prefix: Option<Ident>,
+    pub default_visibility: Option<DefaultVisibility>,
+    pub naming: Option<NamingStrategy>,
+    /// whether the std `#[default]` attribute on a variant generates the `Default` implementation
+    pub std_default: bool,
+    /// the user-defined zero-sized types which are generated within every variant, ex: `markers(Tag<_>)`
+    pub markers: Vec<Type>,
+    /// whether the generated code is reported as a compile error, ex: `#[ctor(debug = true)]`
+    pub debug: bool,
+}
+
+impl CtorStructConfiguration {
+    fn from_variant(configuration: &CtorEnumConfiguration, variant_name: Ident, item_visibility: &Visibility) -> Self {
+        Self {
+            definitions: vec![variant_definition(configuration, variant_name, item_visibility)],
+            is_none: false,
+            ..Default::default()
+        }
+    }
+}
+
+/// The constructor of a variant which does not declare its own, named after the variant
+fn variant_definition(configuration: &CtorEnumConfiguration, variant_name: Ident, item_visibility: &Visibility) -> CtorDefinition {
+    CtorDefinition {
+        visibility: match &configuration.default_visibility {
+            Some(default_visibility) => default_visibility.resolve(item_visibility),
+            None => Visibility::Public(Pub { span: Span::mixed_site() }),
+        },
+        ident: match &configuration.prefix {
+            None => variant_name,
+            Some(prefix) => format_ident!("{}_{}", prefix, variant_name, span = variant_name.span()),
+        },
+        attrs: Default::default(),
+        parameters: Vec::new(),
+        parameter_order: Vec::new(),
+        generics: Default::default(),
+        specialization: None,
+        error_type: None,
+    }
+}
+
+impl CtorEnumConfiguration {
+    /// Fills the properties which are not declared by the enum with the package-wide defaults of the metadata
+    pub fn apply_metadata(&mut self, metadata: &Metadata) {
+        self.prefix = self.prefix.take().or_else(|| metadata.prefix.clone());
+        self.default_visibility = self.default_visibility.take().or_else(|| metadata.visibility.clone());
+        self.naming = self.naming.or(Some(metadata.naming));
+    }
+}
+
+impl Parse for CtorEnumConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut configuration = CtorEnumConfiguration::default();
+        loop {
+            match input.parse::<EnumConfigItem>()? {
+                EnumConfigItem::Visibility { visibility } => {
+                    configuration.default_visibility = Some(visibility)
+                }
+                EnumConfigItem::Prefix { prefix } => configuration.prefix = Some(prefix),
+                EnumConfigItem::StdDefault => configuration.std_default = true,
+                EnumConfigItem::Markers { markers } => configuration.markers.extend(markers),
+                EnumConfigItem::Naming { naming } => configuration.naming = Some(naming),
+                EnumConfigItem::Debug { debug } => configuration.debug = debug,
+            }
+            if input.parse::<Comma>().is_err() {
+                break;
+            }
+        }
+        Ok(configuration)
+    }
+}
+
+impl Parse for EnumConfigItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let property = input.parse::<Ident>()?;
+        let property_name = property.to_string();
+
+        if property_name == MARKERS && input.peek(token::Paren) {
+            return consume_delimited(input, Delimiter::Parenthesis, |types| {
+                Ok(EnumConfigItem::Markers { markers: Punctuated::<Type, Comma>::parse_terminated(types)?.into_iter().collect() })
+            });
+        }
+
+        input.parse::<token::Eq>()?;
+
+        Ok(match property_name.as_str() {
+            VIS | VISIBILITY => EnumConfigItem::Visibility { visibility: input.parse()?, },
+            PREFIX => EnumConfigItem::Prefix { prefix: input.parse()?, },
+            DEBUG => EnumConfigItem::Debug { debug: input.parse::<LitBool>()?.value },
+            DEFAULT => {
+                let value = input.parse::<Ident>()?;
+                if value != STD {
+                    return Err(unknown_property_error(value.span(), &value.to_string(), ENUM_DEFAULT_VALUES));
+                }
+                EnumConfigItem::StdDefault
+            }
+            NAMING => {
+                let value = input.parse::<Ident>()?;
+                EnumConfigItem::Naming {
+                    naming: match value.to_string().as_str() {
+                        SNAKE_CASE => NamingStrategy::SnakeCase,
+                        PRESERVE => NamingStrategy::Preserve,
+                        value_name => return Err(unknown_property_error(value.span(), value_name, NAMING_VALUES)),
+                    }
+                }
+            }
+            _ => return Err(unknown_property_error(property.span(), &property_name, ENUM_CTOR_PROPS)),
+        })
+    }
+}
+
+pub(crate) fn create_enum_token_stream(derive_input: DeriveInput, metadata: &Metadata) -> TokenStream {
+    if let Data::Enum(data) = derive_input.data {
+        let mut configuration = match try_parse_attributes_with_default(&derive_input.attrs, || { 
+            CtorEnumConfiguration::default()
+        }) {
+            Ok(config) => config,
+            Err(err) => return err.to_compile_error(),
+        };
+        configuration.apply_metadata(metadata);
+
+        let ident = derive_input.ident.clone();
+        let is_debug = configuration.debug;
+        let expansion = create_ctor_enum_impl(
+            derive_input.ident,
+            derive_input.vis,
+            derive_input.generics,
+            data.variants,
+            configuration,
+            metadata,
+        );
+        return debug_expansion(&ident, is_debug, expansion);
+    }
+    Error::new(derive_input.ident.span(), "Expected an enum").into_compile_error()
+}
+
+fn create_ctor_enum_impl(
+    ident: Ident,
+    item_visibility: Visibility,
+    generics: Generics,
+    variants: Punctuated<Variant, Comma>,
+    configuration: CtorEnumConfiguration,
+    metadata: &Metadata,
+) -> TokenStream {
+    let mut methods = Vec::new();
+    let mut specialized_impls = Vec::new();
+    let mut default_method = None;
+    let mut default_generics = None;
+    // the `Default` implementation only exists if the variant it constructs does
+    let mut default_cfg_attrs = Vec::new();
+    let mut diagnostics = Diagnostics::default();
+    let mut validator = Validator::default();
+    let impl_attributes = generated_impl_attributes();
+    let method_attributes = metadata.method_attributes();
+
+    #[cfg(feature = "shorthand")]
+    let warnings = if metadata.shorthand_warnings {
+        crate::fields::foreign_attribute_warnings(variants.iter().flat_map(|variant| &variant.fields))
+    } else {
+        quote! {}
+    };
+    #[cfg(not(feature = "shorthand"))]
+    let warnings = quote! {};
+
+    for variant in variants {
+        let variant_code = match &variant.fields {
+            Fields::Named(_) => 0,
+            Fields::Unnamed(_) => 1,
+            Fields::Unit => 2,
+        };
+
+        let variant_name = variant.ident;
+        let cfg_attrs = cfg_attributes(&variant.attrs);
+        let Some(mut variant_config) = diagnostics.check(try_parse_attributes_with_default(&variant.attrs, || {
+            CtorStructConfiguration::from_variant(&configuration, variant_name.clone(), &item_visibility)
+        })) else {
+            continue;
+        };
+
+        // stop generation of method if none
+        if variant_config.is_none {
+            continue;
+        }
+        variant_config.apply_metadata(&item_visibility, metadata, || {
+            variant_definition(&configuration, variant_name.clone(), &item_visibility)
+        });
+
+        let mut definitions = variant_config.definitions;
+        let markers: Vec<Type> = configuration.markers.iter().cloned().chain(variant_config.markers).collect();
+        // in std compatibility mode, `#[default]` on a variant additionally generates the `Default` implementation
+        let is_std_default = variant.attrs.iter()
+            .any(|attribute| matches!(&attribute.meta, Meta::Path(path) if path.is_ident(DEFAULT)));
+        if configuration.std_default && is_std_default {
+            definitions.push(CtorDefinition {
+                visibility: Visibility::Inherited,
+                ident: Ident::new(DEFAULT, variant_name.span()),
+                attrs: [CtorAttribute::Default].into_iter().collect(),
+                ..Default::default()
+            });
+        }
+
+        for (i, def) in definitions.iter().enumerate() {
+            let Some(mut meta) = diagnostics.check(generate_ctor_meta(&definitions, i, &variant.fields, &generics, &markers, &metadata.runtime)) else {
+                continue;
+            };
+            validator.check_meta(def, &meta, &mut diagnostics);
+            meta.infer_bounds(&generics);
+            diagnostics.check(meta.check_const(def));
+
+            let is_default = def.attrs.contains(&CtorAttribute::Default);
+
+            if let Some(arguments) = &def.specialization {
+                if is_default {
+                    diagnostics.push(Error::new(arguments.span(), SPECIALIZED_DEFAULT_ERR_MSG));
+                    continue;
+                }
+                if diagnostics.check(meta.specialize(&generics, arguments)).is_none() {
+                    continue;
+                }
+            }
+
+            let (method_generics, method_where_clause) = if is_default {
+                default_generics = diagnostics.check(meta.default_generics(&generics));
+                (quote! {}, quote! {})
+            } else {
+                meta.method_generics()
+            };
+            let Some(return_type) = diagnostics.check(meta.return_type()) else {
+                continue;
+            };
+            let is_fallible = meta.is_fallible();
+            let field_entries = meta.field_entries();
+            let field_idents = meta.field_idents;
+            let parameter_fields = meta.parameter_fields;
+            let parameter_bindings = meta.parameter_bindings;
+            let specialized_items = meta.specialized_items;
+            let generated_fields = meta.generated_fields;
+
+            // the method of a `Default` implementation cannot declare a visibility
+            let visibility = (!is_default).then_some(&def.visibility);
+            // preserved names are usually not snake case, which is intended
+            let (name, naming_attribute) = match configuration.naming {
+                Some(NamingStrategy::Preserve) => (Ok(def.ident.clone()), quote! { #[allow(non_snake_case)] }),
+                _ => (convert_to_snakecase(def.ident.clone()), quote! {}),
+            };
+            let Some(name) = diagnostics.check(name) else {
+                continue;
+            };
+            validator.check_definition(def, &name, &mut diagnostics);
+
+            let const_tkn = if def.attrs.contains(&CtorAttribute::Const) {
+                quote! { const }
+            } else {
+                quote! {}
+            };
+
+            let mut enum_generation = if variant_code == 0 {
+                quote! { Self::#variant_name { #(#field_entries),* } }
+            } else if variant_code == 1 {
+                quote! { Self::#variant_name ( #(#field_idents),* ) }
+            } else {
+                quote! { Self::#variant_name }
+            };
+            
+            if is_fallible {
+                enum_generation = quote! { ::core::result::Result::Ok(#enum_generation) };
+            }
+
+            let method_token_stream = quote! {
+                #method_attributes
+                #naming_attribute
+                #(#cfg_attrs)*
+                #visibility #const_tkn fn #name #method_generics(#(#parameter_fields),*) -> #return_type #method_where_clause {
+                    #(#specialized_items)*
+                    #(#parameter_bindings)*
+                    #(#generated_fields)*
+                    #enum_generation
+                }
+            };
+
+            if is_default {
+                default_method = Some(method_token_stream);
+                default_cfg_attrs = cfg_attrs.clone();
+            } else if let Some(arguments) = &def.specialization {
+                specialized_impls.push(quote! {
+                    #(#cfg_attrs)*
+                    #impl_attributes
+                    impl #ident #arguments {
+                        #method_token_stream
+                    }
+                });
+            } else {
+                methods.push(method_token_stream);
+            }
+        }
+    }
+
+    if let Err(err) = diagnostics.finish() {
+        return err.into_compile_error();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let default_impl = if let (Some(def_method), Some(def_generics)) = (default_method, default_generics) {
+        let (impl_generics, ty_generics, where_clause) = def_generics.split_for_impl();
+        quote! {
+            #(#default_cfg_attrs)*
+            #impl_attributes
+            impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                #def_method
+            }
+        }
+    } else {
+        quote! {}
+    };
+    
+    quote! {
+        #impl_attributes
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#methods)*
+        }
+        #(#specialized_impls)*
+        #default_impl
+        #warnings
+    }
+}
+
+fn convert_to_snakecase(method_ident: Ident) -> Result<Ident, Error> {
+    let ident_string = method_ident.to_string();
+    let trimmed_start_str = ident_string.trim_start_matches('_');
+    let trimmed_start_end_str = trimmed_start_str.trim_end_matches('_');
+
+    let leading_underscore_count = ident_string.len() - trimmed_start_str.len();
+    let trailing_underscore_count = trimmed_start_str.len() - trimmed_start_end_str.len();
+
+    let snake_case = "_".repeat(leading_underscore_count)
+        + &ident_string.to_snake_case()
+        + &"_".repeat(trailing_underscore_count);
+
+    syn::parse_str(&adjust_keyword_ident(snake_case))
+}
+
+#[test]
+fn test_convert_to_snakecase() {
+    assert_eq!(
+        convert_to_snakecase(Ident::new("A", Span::mixed_site())).unwrap(),
+        Ident::new("a", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("Test", Span::mixed_site())).unwrap(),
+        Ident::new("test", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("Test1", Span::mixed_site())).unwrap(),
+        Ident::new("test1", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("ONETWO", Span::mixed_site())).unwrap(),
+        Ident::new("onetwo", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("OneTwo", Span::mixed_site())).unwrap(),
+        Ident::new("one_two", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("__Abc__", Span::mixed_site())).unwrap(),
+        Ident::new("__abc__", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("A_B", Span::mixed_site())).unwrap(),
+        Ident::new("a_b", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("A_b", Span::mixed_site())).unwrap(),
+        Ident::new("a_b", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("abCdEf", Span::mixed_site())).unwrap(),
+        Ident::new("ab_cd_ef", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("one_2_three", Span::mixed_site())).unwrap(),
+        Ident::new("one_2_three", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("ending_", Span::mixed_site())).unwrap(),
+        Ident::new("ending_", Span::mixed_site())
+    );
+    assert_eq!(
+        convert_to_snakecase(Ident::new("endinG_", Span::mixed_site())).unwrap(),
+        Ident::new("endin_g_", Span::mixed_site())
+    );
+}
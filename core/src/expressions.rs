@@ -0,0 +1,400 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use proc_macro2::{Delimiter, Group, Ident, Literal, Spacing, TokenStream, TokenTree};
+use syn::{Error, Lit};
+
+use crate::CtorParameter;
+use crate::constants::{EXPR_RAW_ERR_MSG, EXPR_RAW_MARKER as RAW, UNUSED_PARAM_ERR_MSG};
+use crate::fields::{GeneratedField, ParameterField};
+
+/// A reference made from within a field expression to another field of the constructor
+pub(crate) enum FieldReference {
+    /// The final (converted or generated) value of a field, ex: `items`
+    Value(Ident),
+    /// The unconverted parameter of a field, ex: `raw!(items)`
+    Raw(Ident),
+}
+
+/// Collects all identifiers within the expression which could refer to a field of the constructor.
+/// Identifiers used as paths, method names, struct-literal keys, or macro names are ignored, as are the
+/// names bound locally by `let`, `if let`, `while let`, `for`, or the parameters of a closure. Arguments captured by a
+/// format string passed to a macro (ex: `format!("{value}")`) are collected as well.
+pub(crate) fn collect_references(stream: &TokenStream, references: &mut Vec<FieldReference>) -> Result<(), Error> {
+    collect_scoped_references(stream, Vec::new(), false, references)
+}
+
+fn collect_scoped_references(
+    stream: &TokenStream,
+    mut locals: Vec<Ident>,
+    is_macro_input: bool,
+    references: &mut Vec<FieldReference>,
+) -> Result<(), Error> {
+    let tokens: Vec<TokenTree> = stream.clone().into_iter().collect();
+    // the bindings of a `let` statement, which come into scope once the statement ends
+    let mut pending_locals = Vec::new();
+    // the bindings of an `if let`, `while let`, or `for`, which are only in scope within the following block
+    let mut block_locals: Option<Vec<Ident>> = None;
+    // the number of locals before the parameters of a closure, whose body ends at the next `,` or `;`
+    let mut closure_scope: Option<usize> = None;
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            TokenTree::Group(group) => {
+                let mut scope = locals.clone();
+                if group.delimiter() == Delimiter::Brace {
+                    scope.extend(block_locals.take().unwrap_or_default());
+                }
+                let is_macro_group = i > 0 && matches!(&tokens[i - 1], TokenTree::Punct(p) if p.as_char() == '!');
+                collect_scoped_references(&group.stream(), scope, is_macro_group, references)?;
+            }
+            TokenTree::Literal(literal) if is_macro_input => {
+                collect_format_captures(literal, &locals, references);
+            }
+            TokenTree::Punct(p) if p.as_char() == '|' && is_expression_start(&tokens, i) => {
+                closure_scope.get_or_insert(locals.len());
+                i = collect_closure_bindings(&tokens, i + 1, &mut locals);
+                continue;
+            }
+            TokenTree::Punct(p) if p.as_char() == ',' || p.as_char() == ';' => {
+                if let Some(scope) = closure_scope.take() {
+                    locals.truncate(scope);
+                }
+                if p.as_char() == ';' {
+                    locals.append(&mut pending_locals);
+                }
+            }
+            TokenTree::Ident(keyword) if keyword == "let" || keyword == "for" => {
+                let is_block_scoped = keyword == "for" || i > 0 && matches!(&tokens[i - 1],
+                    TokenTree::Ident(previous) if previous == "if" || previous == "while");
+                let mut bindings = Vec::new();
+                i = collect_bindings(&tokens, i + 1, &mut bindings);
+                if is_block_scoped {
+                    block_locals = Some(bindings);
+                } else {
+                    pending_locals.extend(bindings);
+                }
+                continue;
+            }
+            TokenTree::Ident(ident) => {
+                if let Some(raw) = parse_raw_marker(&tokens[i..])? {
+                    references.push(FieldReference::Raw(raw));
+                    i += 3;
+                    continue;
+                }
+                let after_access = i > 0 && matches!(&tokens[i - 1],
+                    TokenTree::Punct(p) if p.as_char() == '.' || p.as_char() == '\'')
+                    || i > 1 && matches!(&tokens[i - 2],
+                    TokenTree::Punct(p) if p.as_char() == ':' && p.spacing() == Spacing::Joint);
+                let before_path = matches!(tokens.get(i + 1),
+                    Some(TokenTree::Punct(p)) if p.as_char() == ':' || p.as_char() == '!');
+                if !after_access && !before_path && !locals.contains(ident) {
+                    references.push(FieldReference::Value(ident.clone()));
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Collects the names bound by the pattern starting at `start`, returning the index of the token ending it
+/// (`=`, `;`, or `in`). A type annotation following the pattern is skipped.
+fn collect_bindings(tokens: &[TokenTree], start: usize, bindings: &mut Vec<Ident>) -> usize {
+    let is_single_colon = |i: usize| matches!(&tokens[i], TokenTree::Punct(p) if p.as_char() == ':'
+        && p.spacing() == Spacing::Alone
+        && !matches!(i.checked_sub(1).map(|j| &tokens[j]), Some(TokenTree::Punct(p)) if p.as_char() == ':'));
+    let is_end = |token: &TokenTree| match token {
+        TokenTree::Punct(p) => p.as_char() == ';' || p.as_char() == '=' && p.spacing() == Spacing::Alone,
+        TokenTree::Ident(ident) => ident == "in",
+        _ => false,
+    };
+
+    let mut end = start;
+    while end < tokens.len() && !is_end(&tokens[end]) && !is_single_colon(end) {
+        end += 1;
+    }
+    collect_pattern_bindings(&tokens[start..end], bindings);
+    while end < tokens.len() && !is_end(&tokens[end]) {
+        end += 1;
+    }
+    end
+}
+
+/// Whether the token at `i` begins an expression (rather than continuing one), ex: the `|` of a closure as opposed to
+/// a bitwise or
+fn is_expression_start(tokens: &[TokenTree], i: usize) -> bool {
+    match i.checked_sub(1).map(|j| &tokens[j]) {
+        None => true,
+        Some(TokenTree::Punct(p)) => p.as_char() != '?',
+        Some(TokenTree::Ident(ident)) => ident == "move" || ident == "return",
+        _ => false,
+    }
+}
+
+/// Collects the names bound by the parameters of a closure starting at `start` (after its opening `|`), returning the
+/// index of the token following its closing `|`. The type annotations of the parameters are skipped.
+fn collect_closure_bindings(tokens: &[TokenTree], start: usize, bindings: &mut Vec<Ident>) -> usize {
+    let end = (start..tokens.len())
+        .find(|&i| matches!(&tokens[i], TokenTree::Punct(p) if p.as_char() == '|'))
+        .unwrap_or(tokens.len());
+    for parameter in tokens[start..end].split(|token| matches!(token, TokenTree::Punct(p) if p.as_char() == ',')) {
+        let pattern_end = (0..parameter.len())
+            .find(|&i| matches!(&parameter[i], TokenTree::Punct(p) if p.as_char() == ':' && p.spacing() == Spacing::Alone)
+                && !matches!(i.checked_sub(1).map(|j| &parameter[j]), Some(TokenTree::Punct(p)) if p.as_char() == ':'))
+            .unwrap_or(parameter.len());
+        collect_pattern_bindings(&parameter[..pattern_end], bindings);
+    }
+    end + 1
+}
+
+fn collect_pattern_bindings(tokens: &[TokenTree], bindings: &mut Vec<Ident>) {
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Group(group) => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                collect_pattern_bindings(&inner, bindings);
+            }
+            TokenTree::Ident(ident) if ident != "mut" && ident != "ref" && ident != "_" => {
+                // paths, tuple-struct and struct patterns, and field names of struct patterns are not bindings
+                let after_path = i > 0 && matches!(&tokens[i - 1], TokenTree::Punct(p) if p.as_char() == ':');
+                let before_path = match tokens.get(i + 1) {
+                    Some(TokenTree::Punct(p)) => p.as_char() == ':' || p.as_char() == '!',
+                    Some(TokenTree::Group(group)) => group.delimiter() != Delimiter::Bracket,
+                    _ => false,
+                };
+                if !after_path && !before_path {
+                    bindings.push(ident.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collects the arguments captured by a format string, ex: `value` and `width` within `"{value:width$}"`
+fn collect_format_captures(literal: &Literal, locals: &[Ident], references: &mut Vec<FieldReference>) {
+    let Lit::Str(format) = Lit::new(literal.clone()) else {
+        return;
+    };
+    let format = format.value();
+    let mut rest = format.as_str();
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        if let Some(escaped) = rest.strip_prefix('{') {
+            rest = escaped;
+            continue;
+        }
+        let end = rest.find('}').unwrap_or(rest.len());
+        let (argument, spec) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+        let arguments = core::iter::once(argument.trim())
+            .chain(spec.split('$').rev().skip(1).map(|part| {
+                part.rsplit(|c: char| !c.is_alphanumeric() && c != '_').next().unwrap_or_default()
+            }));
+        for argument in arguments {
+            if let Ok(mut ident) = syn::parse_str::<Ident>(argument) {
+                ident.set_span(literal.span());
+                if !locals.contains(&ident) {
+                    references.push(FieldReference::Value(ident));
+                }
+            }
+        }
+        rest = &rest[end..];
+    }
+}
+
+/// Replaces every `raw!(ident)` marker within the expression with the identifier it wraps
+pub(crate) fn strip_raw_markers(stream: &TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = stream.clone().into_iter().collect();
+    let mut output = TokenStream::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Ok(Some(raw)) = parse_raw_marker(&tokens[i..]) {
+            output.extend([TokenTree::Ident(raw)]);
+            i += 3;
+            continue;
+        }
+        output.extend([match &tokens[i] {
+            TokenTree::Group(group) => {
+                let mut stripped = Group::new(group.delimiter(), strip_raw_markers(&group.stream()));
+                stripped.set_span(group.span());
+                TokenTree::Group(stripped)
+            }
+            token => token.clone(),
+        }]);
+        i += 1;
+    }
+    output
+}
+
+fn parse_raw_marker(tokens: &[TokenTree]) -> Result<Option<Ident>, Error> {
+    let (marker, group) = match tokens {
+        [TokenTree::Ident(marker), TokenTree::Punct(bang), TokenTree::Group(group), ..]
+            if marker == RAW && bang.as_char() == '!' && bang.spacing() == Spacing::Alone
+                && group.delimiter() == Delimiter::Parenthesis => (marker, group),
+        _ => return Ok(None)
+    };
+    let mut inner = group.stream().into_iter();
+    match (inner.next(), inner.next()) {
+        (Some(TokenTree::Ident(ident)), None) => Ok(Some(ident)),
+        _ => Err(Error::new(marker.span(), format!("Expected a single field name within {}!(...)", RAW)))
+    }
+}
+
+/// Orders the generated fields so that every field is generated after the fields its expression
+/// depends on, and before the fields whose raw parameter it references.
+pub(crate) fn order_generated_fields(
+    generated_fields: Vec<GeneratedField>,
+    parameter_fields: &[ParameterField],
+) -> Result<Vec<GeneratedField>, Error> {
+    let position = |ident: &Ident| generated_fields.iter().position(|f| f.field_ident == *ident);
+
+    // requirements[i] contains all fields which must be generated before field i
+    let mut requirements: Vec<Vec<usize>> = vec![Vec::new(); generated_fields.len()];
+    for (i, field) in generated_fields.iter().enumerate() {
+        let Some(expression) = field.configuration.expression() else {
+            continue;
+        };
+
+        let mut references = Vec::new();
+        collect_references(expression, &mut references)?;
+
+        for reference in references {
+            match reference {
+                FieldReference::Value(ident) => match position(&ident) {
+                    Some(j) if j != i => requirements[i].push(j),
+                    _ => {}
+                },
+                FieldReference::Raw(ident) => {
+                    if !parameter_fields.iter().any(|p| p.field_ident == ident) {
+                        return Err(Error::new(ident.span(),
+                            EXPR_RAW_ERR_MSG.replace("{field}", &ident.to_string())));
+                    }
+                    match position(&ident) {
+                        Some(j) if j != i => requirements[j].push(i),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    let mut emitted = vec![false; generated_fields.len()];
+    let mut order = Vec::with_capacity(generated_fields.len());
+    while order.len() < generated_fields.len() {
+        let next = (0..generated_fields.len())
+            .find(|&i| !emitted[i] && requirements[i].iter().all(|&j| emitted[j]));
+        match next {
+            Some(i) => {
+                emitted[i] = true;
+                order.push(i);
+            }
+            None => return Err(cycle_error(&generated_fields, &requirements, &emitted)),
+        }
+    }
+
+    let mut slots: Vec<Option<GeneratedField>> = generated_fields.into_iter().map(Some).collect();
+    Ok(order.into_iter().filter_map(|i| slots[i].take()).collect())
+}
+
+/// Ensures every parameter declared by a constructor definition is referenced by a field expression
+pub(crate) fn check_unused_parameters(
+    parameters: &[CtorParameter],
+    generated_fields: &[GeneratedField],
+) -> Result<(), Error> {
+    if parameters.is_empty() {
+        return Ok(());
+    }
+
+    let mut references = Vec::new();
+    for field in generated_fields {
+        if let Some(expression) = field.configuration.expression() {
+            collect_references(expression, &mut references)?;
+        }
+    }
+
+    let mut errors: Option<Error> = None;
+    for parameter in parameters {
+        let is_used = references.iter().any(|reference| match reference {
+            FieldReference::Value(ident) | FieldReference::Raw(ident) => *ident == parameter.ident,
+        });
+        if !is_used {
+            let error = Error::new(parameter.ident.span(),
+                UNUSED_PARAM_ERR_MSG.replace("{param}", &parameter.ident.to_string()));
+            match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn cycle_error(generated_fields: &[GeneratedField], requirements: &[Vec<usize>], emitted: &[bool]) -> Error {
+    // every remaining field has at least one remaining requirement, so walking them must revisit a field
+    let mut path: Vec<usize> = Vec::new();
+    let mut current = (0..emitted.len()).find(|&i| !emitted[i]).unwrap_or_default();
+    while !path.contains(&current) {
+        path.push(current);
+        current = requirements[current].iter().copied().find(|&j| !emitted[j]).unwrap_or(current);
+    }
+    let start = path.iter().position(|&i| i == current).unwrap_or_default();
+    let cycle = path[start..].iter().chain([&current])
+        .map(|&i| format!("`{}`", generated_fields[i].field_ident))
+        .collect::<Vec<String>>()
+        .join(" -> ");
+    Error::new(generated_fields[current].span, format!("Cyclic dependency between generated fields: {}", cycle))
+}
+
+#[test]
+fn test_collect_references() {
+    let mut references = Vec::new();
+    collect_references(&quote::quote! { a.len() + b::c(raw!(d)) + e!(f) + Foo { g: h } }, &mut references).unwrap();
+    let names: Vec<String> = references.iter().map(|r| match r {
+        FieldReference::Value(ident) => ident.to_string(),
+        FieldReference::Raw(ident) => format!("raw {}", ident),
+    }).collect();
+    assert_eq!(vec!["a", "raw d", "f", "Foo", "h"], names);
+}
+
+#[test]
+fn test_collect_references_skips_local_bindings() {
+    let mut references = Vec::new();
+    collect_references(&quote::quote! {{
+        let (a, mut b): (u32, u32) = (c, d);
+        if let Some(e) = f { a + b + e } else { e }
+        for g in h.iter() { g; }
+        let total = i.iter().map(|j, (k, _): (u32, u32)| j * k | l).sum() + j;
+        let m = move || total;
+        g
+    }}, &mut references).unwrap();
+    // keywords are collected as well, which is harmless since no field can be named after one
+    let names: Vec<String> = references.iter().map(|r| match r {
+        FieldReference::Value(ident) | FieldReference::Raw(ident) => ident.to_string(),
+    }).filter(|name| !["if", "else", "in", "move"].contains(&name.as_str())).collect();
+    assert_eq!(vec!["c", "d", "f", "e", "h", "i", "l", "j", "g"], names);
+}
+
+#[test]
+fn test_collect_format_captures() {
+    let mut references = Vec::new();
+    collect_references(&quote::quote! {{
+        let a = 1;
+        format!("{a} {b:?} {{c}} {d:>width$.precision$} {0}", e)
+    }}, &mut references).unwrap();
+    let names: Vec<String> = references.iter().map(|r| match r {
+        FieldReference::Value(ident) | FieldReference::Raw(ident) => ident.to_string(),
+    }).collect();
+    assert_eq!(vec!["b", "d", "precision", "width", "e"], names);
+}
+
+#[test]
+fn test_strip_raw_markers() {
+    let stripped = strip_raw_markers(&quote::quote! { raw!(a).len() + (raw!(b)) });
+    assert_eq!(quote::quote! { a.len() + (b) }.to_string(), stripped.to_string());
+}
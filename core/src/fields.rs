@@ -0,0 +1,1386 @@
+extern crate alloc;
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use proc_macro2::{Delimiter, Punct, Span, TokenTree};
+use proc_macro2::Spacing::Alone;
+use quote::{quote, TokenStreamExt, ToTokens};
+use syn::{AngleBracketedGenericArguments, Attribute, ConstParam, Error, Expr, ExprPath, Fields, GenericArgument, GenericParam, Generics, Ident, Lifetime, Path, PathArguments, PathSegment, LitInt, LitStr, parse2, parse_quote, token, Token, Type, TraitBoundModifier, TypeParam, TypeParamBound, TypePath, WherePredicate};
+use syn::parse::{Parse, ParseStream};
+use syn::parse::discouraged::{AnyDelimiter, Speculative};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Comma;
+use syn::visit_mut::{self, VisitMut};
+
+use crate::diagnostics::{suggestion, unknown_property_error, Diagnostics};
+use crate::expressions::{check_unused_parameters, order_generated_fields, strip_raw_markers};
+use crate::{cfg_attributes, consume_delimited, CtorAttribute, CtorDefinition, is_marker_type};
+use crate::constants::{CONST_DEFAULT_ERR_MSG, CONST_FIELD_ERR_MSG, CTOR_INDEX_ERR_MSG, CTOR_NAME_ERR_MSG, CTOR_WORD, DUPLICATE_PARAM_ERR_MSG, FIELD_PROP_CLONED as CLONED, FIELD_PROP_DEFAULT as DEFAULT, FIELD_PROP_EXPR as EXPR, FIELD_PROP_INTO as INTO, FIELD_PROP_ITER as ITER, FIELD_PROP_REPEAT as REPEAT, FIELD_PROP_FROM_FN as FROM_FN, FIELD_PROP_MARKER as MARKER, FIELD_PROP_NAME as NAME, FIELD_PROP_ORDER as ORDER, FALLIBLE_DEFAULT_ERR_MSG, FALLIBLE_ERROR_TYPE_ERR_MSG, GENERIC_DEFAULT_ERR_MSG, ITER_CONVERSION_INTO as ITER_INTO, ITER_CONVERSION_TRY_INTO as ITER_TRY_INTO, ITER_INFER_ERR_MSG, ITER_SCALAR_ERR_MSG, MULTIPLE_PROP_ERR_MSG, ORDER_PARAM_ERR_MSG, SPECIALIZATION_ERR_MSG, SPECIALIZED_ASSOC_ERR_MSG, UNKNOWN_PARAM_ERR_MSG};
+
+const FIELD_PROPS: &[&str] = &[CLONED, DEFAULT, EXPR, INTO, ITER, REPEAT, FROM_FN, MARKER];
+// must match the attributes registered by the derive in lib.rs
+#[cfg(feature = "shorthand")]
+const SHORTHAND_ATTRIBUTES: &[&str] = &[CLONED, DEFAULT, EXPR, INTO, ITER];
+
+/// Represents a configuration on a struct field
+///
+/// # Example
+///
+/// ```
+/// use derive_ctor_core::fields::{FieldConfig, FieldConfigProperty};
+///
+/// // the contents of #[ctor(default = [1, empty])]
+/// let config: FieldConfig = syn::parse_quote!(default = [1, empty]);
+/// assert!(matches!(config.property, Some(FieldConfigProperty::Default)));
+/// assert_eq!(2, config.applications.len());
+/// ```
+
+#[derive(Clone, Default)]
+pub struct FieldConfig {
+    pub property: Option<FieldConfigProperty>,
+    pub applications: Vec<CtorApplication>,
+    /// the name of the generated parameter, ex: `#[ctor(name = width)]`
+    pub parameter_name: Option<Ident>,
+    /// the position of the generated parameter, ex: `#[ctor(order = 0)]`
+    pub parameter_order: Option<LitInt>,
+}
+
+/// A reference to a constructor definition, either by its position in the `#[ctor(...)]` list
+/// or by its name
+#[derive(Clone)]
+pub enum CtorApplication {
+    Index(LitInt),
+    Name(Ident),
+}
+
+#[derive(Clone)]
+pub enum FieldConfigProperty {
+    Cloned,
+    Default,
+    Into,
+    Iter {
+        /// the item type of the iterator, inferred from the field type if not provided
+        iter_type: Option<Type>,
+        conversion: IterConversion,
+    },
+    Expression {
+        expression: proc_macro2::TokenStream,
+        inputs: Vec<ExpressionInput>,
+        self_referencing: bool,
+    },
+    /// replaces `Default` within const constructors, generated as `<T as ConstDefault>::DEFAULT`
+    ConstDefault {
+        /// the path of the runtime crate which declares `ConstDefault`, ex: `::derive_ctor_runtime`
+        runtime: Path,
+    },
+    /// fills every slot of an array field with a clone of the value
+    Repeat {
+        expression: proc_macro2::TokenStream,
+    },
+    /// generates an array field by calling the closure with the index of each slot
+    FromFn {
+        expression: proc_macro2::TokenStream,
+    },
+}
+
+/// The conversion applied to each item of an `iter` property before it is collected
+#[derive(Clone, Copy, PartialEq)]
+pub enum IterConversion {
+    /// items are collected as-is, ex: `iter(String)`
+    None,
+    /// items are converted with `Into::into`, ex: `iter(into String)`
+    Into,
+    /// items are converted with `TryInto::try_into`, making the constructor fallible, ex: `iter(try_into u8)`
+    TryInto,
+}
+
+/// An input parameter declared by an expression, ex: `expr((x: f32, y: f32) -> Point { x, y })`.
+/// Inputs declared through `expr(TYPE -> EXPRESSION)` have no ident and are named after their field.
+#[derive(Clone)]
+pub struct ExpressionInput {
+    pub ident: Option<Ident>,
+    pub input_type: Type,
+}
+
+#[derive(Default)]
+pub struct ConstructorMeta {
+    pub field_idents: Vec<Ident>,
+    /// the `#[cfg(...)]` attributes of each field, carried onto everything generated for the field
+    pub field_cfg_attrs: Vec<Vec<Attribute>>,
+    pub parameter_fields: Vec<ParameterField>,
+    pub parameter_bindings: Vec<ParameterBinding>,
+    pub generated_fields: Vec<GeneratedField>,
+    pub generic_parameters: Vec<GenericParameter>,
+    /// the generics and where clause declared by the constructor definition, ex: `new<U> where T: Clone`
+    pub generics: Generics,
+    /// the error type declared by the constructor definition, ex: `new(error = ParseError)`
+    pub error_type: Option<Type>,
+    /// the error types of the fallible fields of the constructor, ex: `<TItemsItem as TryInto<u8>>::Error`
+    pub fallible_errors: Vec<(Type, Span)>,
+    /// the local items declaring the generic arguments of a specialized constructor which are referenced by field
+    /// expressions, ex: `type T = u8;` or `const N: usize = 4;`
+    pub specialized_items: Vec<proc_macro2::TokenStream>,
+}
+
+/// A method-level generic parameter which replaces an `impl Trait` parameter type, ex: `TName: Into<String>`
+#[derive(Clone)]
+pub struct GenericParameter {
+    pub ident: Ident,
+    pub bounds: Punctuated<TypeParamBound, Token![+]>,
+}
+
+#[derive(Clone)]
+pub struct ParameterField {
+    pub field_ident: Ident,
+    /// the name used within the constructor signature if it differs from the field
+    pub parameter_ident: Option<Ident>,
+    pub field_type: Type,
+    pub span: Span,
+    pub cfg_attrs: Vec<Attribute>,
+}
+
+/// Binds a renamed parameter back to the ident of its field, ex: `let field = parameter;`
+#[derive(Clone)]
+pub struct ParameterBinding {
+    pub field_ident: Ident,
+    pub parameter_ident: Ident,
+    pub cfg_attrs: Vec<Attribute>,
+}
+
+#[derive(Clone)]
+pub struct GeneratedField {
+    pub field_ident: Ident,
+    pub field_type: Type,
+    pub configuration: FieldConfigProperty,
+    pub span: Span,
+    pub cfg_attrs: Vec<Attribute>,
+    /// the error returned when the iterator filling an array yields the wrong number of items, which is only set
+    /// within fallible constructors (otherwise the constructor panics), ex: `::derive_ctor_runtime::ArrayLengthError`
+    pub array_length_error: Option<Path>,
+}
+
+impl Parse for FieldConfig {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut config = FieldConfig::default();
+
+        loop {
+            // options take the form of NAME = VALUE, which is distinguished from properties with applications by name
+            let fork = input.fork();
+            let option = fork.parse::<Ident>().ok().filter(|_| fork.peek(token::Eq));
+            match option.map(|ident| ident.to_string()).as_deref() {
+                Some(NAME) => {
+                    input.advance_to(&fork);
+                    input.parse::<token::Eq>()?;
+                    config.parameter_name = Some(input.parse()?);
+                }
+                Some(ORDER) => {
+                    input.advance_to(&fork);
+                    input.parse::<token::Eq>()?;
+                    config.parameter_order = Some(input.parse()?);
+                }
+                _ => {
+                    if config.property.is_some() {
+                        return Err(Error::new(input.span(), MULTIPLE_PROP_ERR_MSG));
+                    }
+                    config.property = Some(input.parse()?);
+                    config.parse_applications(input)?;
+                }
+            }
+            if input.is_empty() || input.parse::<Comma>().is_err() {
+                break;
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+impl FieldConfig {
+    fn parse_applications(&mut self, input: ParseStream) -> syn::Result<()> {
+        if input.parse::<token::Eq>().is_err() {
+            return Ok(());
+        }
+
+        // consume constructor specifier ex: 1, 2, 3
+        if let Ok((delim, span, buffer)) = input.parse_any_delimiter() {
+            if delim != Delimiter::Bracket {
+                return Err(Error::new(span.span(), "Expected enclosing brackets"));
+            }
+            loop {
+                self.applications.push(buffer.parse()?);
+                if buffer.parse::<Comma>().is_err() {
+                    break;
+                }
+            }
+        } else if input.peek(LitStr) {
+            // attribute values must be literals, so shorthand attributes list their constructors within a string
+            // ex: #[default = "1, with_defaults"]
+            let applications: LitStr = input.parse()?;
+            self.applications.extend(applications.parse_with(Punctuated::<CtorApplication, Comma>::parse_terminated)?);
+        } else {
+            self.applications.push(input.parse()?);
+        }
+
+        Ok(())
+    }
+}
+
+impl Parse for CtorApplication {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitInt) {
+            return Ok(CtorApplication::Index(input.parse()?));
+        }
+        Ok(CtorApplication::Name(input.parse()?))
+    }
+}
+
+impl FieldConfig {
+    /// Determines whether this configuration applies to the definition at `ctor_index`, erroring if
+    /// any of the referenced constructors do not exist
+    fn applies_to(&self, definitions: &[CtorDefinition], ctor_index: usize) -> Result<bool, Error> {
+        if self.applications.is_empty() {
+            return Ok(true);
+        }
+        let mut applies = false;
+        for application in &self.applications {
+            let index = match application {
+                CtorApplication::Index(lit) => {
+                    let index = lit.base10_parse::<usize>()?;
+                    if index >= definitions.len() {
+                        return Err(Error::new(lit.span(), CTOR_INDEX_ERR_MSG
+                            .replace("{index}", &index.to_string())
+                            .replace("{count}", &definitions.len().to_string())));
+                    }
+                    index
+                }
+                CtorApplication::Name(name) => definitions.iter()
+                    .position(|definition| definition.ident == *name)
+                    .ok_or_else(|| Error::new(name.span(), CTOR_NAME_ERR_MSG
+                        .replace("{name}", &name.to_string())
+                        + &suggestion(&name.to_string(), definitions.iter().map(|d| d.ident.to_string()))))?
+            };
+            applies |= index == ctor_index;
+        }
+        Ok(applies)
+    }
+}
+
+impl FieldConfigProperty {
+    fn is_generated(&self) -> bool {
+        match self {
+            FieldConfigProperty::Cloned => false,
+            FieldConfigProperty::Default => true,
+            FieldConfigProperty::ConstDefault { .. } => true,
+            FieldConfigProperty::Into => false,
+            FieldConfigProperty::Iter { .. } => false,
+            FieldConfigProperty::Expression { self_referencing, inputs, .. } => !self_referencing && inputs.is_empty(),
+            FieldConfigProperty::Repeat { .. } => true,
+            FieldConfigProperty::FromFn { .. } => true,
+        }
+    }
+
+    /// Explains why the property cannot be used within a `const` constructor, if it cannot
+    fn const_incompatibility(&self) -> Option<&'static str> {
+        match self {
+            FieldConfigProperty::Cloned => Some("`cloned` calls `Clone::clone`, which is not a const fn"),
+            FieldConfigProperty::Default => Some("`default` calls `Default::default`, which is not a const fn"),
+            FieldConfigProperty::Into => Some("`into` calls `Into::into`, which is not a const fn"),
+            FieldConfigProperty::Iter { .. } => Some("`iter` consumes an iterator, which cannot be done in a const fn"),
+            FieldConfigProperty::Repeat { .. } | FieldConfigProperty::FromFn { .. } => {
+                Some("the array is filled with `core::array::from_fn`, which is not a const fn")
+            }
+            FieldConfigProperty::Expression { .. } | FieldConfigProperty::ConstDefault { .. } => None,
+        }
+    }
+
+    /// The user-provided expression used to generate the field, if any
+    pub fn expression(&self) -> Option<&proc_macro2::TokenStream> {
+        match self {
+            FieldConfigProperty::Expression { expression, .. }
+            | FieldConfigProperty::Repeat { expression }
+            | FieldConfigProperty::FromFn { expression } => Some(expression),
+            _ => None,
+        }
+    }
+
+    fn expression_mut(&mut self) -> Option<&mut proc_macro2::TokenStream> {
+        match self {
+            FieldConfigProperty::Expression { expression, .. }
+            | FieldConfigProperty::Repeat { expression }
+            | FieldConfigProperty::FromFn { expression } => Some(expression),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for ExpressionInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        Ok(ExpressionInput { ident: Some(ident), input_type: input.parse()? })
+    }
+}
+
+fn parse_expression_inputs(buffer: ParseStream) -> syn::Result<Vec<ExpressionInput>> {
+    // expr((NAME: TYPE, ...) -> EXPRESSION)
+    let fork = buffer.fork();
+    if let Ok(inputs) = consume_delimited(&fork, Delimiter::Parenthesis, |inputs| {
+        Punctuated::<ExpressionInput, Comma>::parse_terminated(inputs)
+    }) {
+        if !inputs.is_empty() && fork.peek(Token![->]) {
+            buffer.advance_to(&fork);
+            buffer.parse::<Token![->]>()?;
+            return Ok(inputs.into_iter().collect());
+        }
+    }
+
+    // expr(TYPE -> EXPRESSION)
+    let fork = buffer.fork();
+    if let Ok(input_type) = fork.parse::<Type>() {
+        if fork.peek(Token![->]) {
+            buffer.advance_to(&fork);
+            buffer.parse::<Token![->]>()?;
+            return Ok(vec![ExpressionInput { ident: None, input_type }]);
+        }
+    }
+
+    Ok(Vec::new())
+}
+
+fn parse_iter_conversion(buffer: ParseStream) -> IterConversion {
+    // iter(into TYPE) or iter(try_into TYPE), while still allowing types such as `into::Type`
+    let fork = buffer.fork();
+    let conversion = match fork.parse::<Ident>() {
+        Ok(ident) if ident == ITER_INTO => IterConversion::Into,
+        Ok(ident) if ident == ITER_TRY_INTO => IterConversion::TryInto,
+        _ => return IterConversion::None,
+    };
+    if fork.peek(Token![::]) || fork.peek(Token![<]) {
+        return IterConversion::None;
+    }
+    buffer.advance_to(&fork);
+    conversion
+}
+
+impl Parse for FieldConfigProperty {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let property: Ident = input.parse()?;
+        let property_name = property.to_string();
+        match property_name.as_str() {
+            CLONED => Ok(FieldConfigProperty::Cloned),
+            // a marker is a zero-sized type which is always generated with its default value
+            DEFAULT | MARKER => Ok(FieldConfigProperty::Default),
+            INTO => Ok(FieldConfigProperty::Into),
+            ITER if !input.peek(token::Paren) => {
+                Ok(FieldConfigProperty::Iter { iter_type: None, conversion: IterConversion::None })
+            }
+            ITER => consume_delimited(input, Delimiter::Parenthesis, |buffer| {
+                let conversion = parse_iter_conversion(buffer);
+                let iter_type = if buffer.is_empty() { None } else { Some(buffer.parse()?) };
+                Ok(FieldConfigProperty::Iter { iter_type, conversion })
+            }),
+            REPEAT => consume_delimited(input, Delimiter::Parenthesis, |buffer| {
+                Ok(FieldConfigProperty::Repeat { expression: buffer.parse()? })
+            }),
+            FROM_FN => consume_delimited(input, Delimiter::Parenthesis, |buffer| {
+                Ok(FieldConfigProperty::FromFn { expression: buffer.parse()? })
+            }),
+            EXPR => {
+                let self_referencing = input.parse::<Token![!]>().is_ok();
+
+                consume_delimited(input, Delimiter::Parenthesis, |buffer| {
+                    let inputs = parse_expression_inputs(buffer)?;
+
+                    Ok(FieldConfigProperty::Expression { self_referencing, inputs,
+                        expression: proc_macro2::TokenStream::parse(buffer)?
+                    })
+                })
+            }
+            _ => Err(unknown_property_error(property.span(), &property_name, FIELD_PROPS))
+        }
+    }
+}
+
+impl ConstructorMeta {
+    /// Generates the generic parameters and `where` clause of the constructor method
+    pub fn method_generics(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let mut generics = self.generics.clone();
+        for GenericParameter { ident, bounds } in &self.generic_parameters {
+            generics.params.push(parse_quote! { #ident });
+            generics.make_where_clause().predicates.push(parse_quote! { #ident: #bounds });
+        }
+        // the errors of fallible fields must be convertible into the declared error type of the constructor
+        if let Some(error_type) = &self.error_type {
+            for (field_error, _) in &self.fallible_errors {
+                generics.make_where_clause().predicates.push(parse_quote! {
+                    #error_type: ::core::convert::From<#field_error>
+                });
+            }
+        }
+        (generics.to_token_stream(), generics.where_clause.to_token_stream())
+    }
+
+    /// Generates the entries of the struct literal, ex: `#[cfg(feature = "metrics")] field`
+    pub fn field_entries(&self) -> Vec<proc_macro2::TokenStream> {
+        self.field_idents.iter().zip(&self.field_cfg_attrs)
+            .map(|(ident, cfg_attrs)| quote! { #(#cfg_attrs)* #ident })
+            .collect()
+    }
+
+    /// Whether the constructor returns a `Result`, either because a field conversion can fail or because
+    /// the definition declares an error type
+    pub fn is_fallible(&self) -> bool {
+        self.error_type.is_some() || !self.fallible_errors.is_empty()
+    }
+
+    /// Generates the return type of the constructor, ex: `Self` or `Result<Self, ParseError>`
+    pub fn return_type(&self) -> Result<proc_macro2::TokenStream, Error> {
+        let error_type = match (&self.error_type, self.fallible_errors.as_slice()) {
+            (Some(error_type), _) => error_type,
+            (None, []) => return Ok(quote! { Self }),
+            (None, [(error_type, _)]) => error_type,
+            (None, [_, (_, span), ..]) => return Err(Error::new(*span, FALLIBLE_ERROR_TYPE_ERR_MSG)),
+        };
+        Ok(quote! { ::core::result::Result<Self, #error_type> })
+    }
+
+    /// Ensures the constructor can be generated as a `const fn`, reporting every incompatible field at its span
+    pub fn check_const(&self, definition: &CtorDefinition) -> Result<(), Error> {
+        if !definition.attrs.contains(&CtorAttribute::Const) {
+            return Ok(());
+        }
+
+        let mut errors: Vec<Error> = Vec::new();
+        if definition.attrs.contains(&CtorAttribute::Default) {
+            errors.push(Error::new(definition.ident.span(), CONST_DEFAULT_ERR_MSG));
+        }
+        for field in &self.generated_fields {
+            if let Some(reason) = field.configuration.const_incompatibility() {
+                errors.push(Error::new(field.span, CONST_FIELD_ERR_MSG
+                    .replace("{field}", &field.field_ident.to_string())
+                    .replace("{reason}", reason)));
+            }
+        }
+
+        errors.into_iter().reduce(|mut combined, error| {
+            combined.combine(error);
+            combined
+        }).map_or(Ok(()), Err)
+    }
+
+    /// Adds the bounds required by the generated fields whose types depend on the type parameters of the
+    /// type, ex: `T: Default` for a `#[ctor(default)]` field of type `T`
+    pub fn infer_bounds(&mut self, type_generics: &Generics) {
+        let type_params: Vec<&Ident> = type_generics.type_params().map(|param| &param.ident).collect();
+        if type_params.is_empty() {
+            return;
+        }
+
+        for field in &self.generated_fields {
+            let bound = match &field.configuration {
+                FieldConfigProperty::Default => quote! { ::core::default::Default },
+                FieldConfigProperty::Cloned => quote! { ::core::clone::Clone },
+                FieldConfigProperty::ConstDefault { runtime } => {
+                    let runtime = respan(runtime, field.span);
+                    quote! { #runtime::ConstDefault }
+                }
+                _ => continue,
+            };
+            let ty = &field.field_type;
+            let references_param = collect_idents(ty.to_token_stream()).iter()
+                .any(|ident| type_params.contains(&ident));
+            if !references_param {
+                continue;
+            }
+            let predicate: WherePredicate = parse_quote! { #ty: #bound };
+            let where_clause = self.generics.make_where_clause();
+            let predicate_string = predicate.to_token_stream().to_string();
+            if !where_clause.predicates.iter().any(|p| p.to_token_stream().to_string() == predicate_string) {
+                where_clause.predicates.push(predicate);
+            }
+        }
+    }
+
+    /// Substitutes the generic parameters of the type with the concrete arguments of a specialized constructor
+    /// within all generated types, bounds, and expressions, ex: `T` -> `u8` for `for <u8>`
+    pub fn specialize(
+        &mut self,
+        type_generics: &Generics,
+        arguments: &AngleBracketedGenericArguments,
+    ) -> Result<(), Error> {
+        if type_generics.params.len() != arguments.args.len() {
+            return Err(Error::new(arguments.span(), SPECIALIZATION_ERR_MSG
+                .replace("{expected}", &type_generics.params.len().to_string())
+                .replace("{found}", &arguments.args.len().to_string())));
+        }
+
+        let mut substitution = GenericSubstitution::new(type_generics, arguments);
+        for parameter in &mut self.parameter_fields {
+            substitution.visit_type_mut(&mut parameter.field_type);
+        }
+        for field in &mut self.generated_fields {
+            substitution.visit_type_mut(&mut field.field_type);
+        }
+        for generic_parameter in &mut self.generic_parameters {
+            for bound in &mut generic_parameter.bounds {
+                substitution.visit_type_param_bound_mut(bound);
+            }
+        }
+        if let Some(where_clause) = &mut self.generics.where_clause {
+            substitution.visit_where_clause_mut(where_clause);
+        }
+        if let Some(error_type) = &mut self.error_type {
+            substitution.visit_type_mut(error_type);
+        }
+        for (error_type, _) in &mut self.fallible_errors {
+            substitution.visit_type_mut(error_type);
+        }
+        let substitutions = substitution.finish()?;
+
+        // expressions are not parsed, so the generic parameters they reference are declared as local items instead
+        let expression_idents: Vec<Ident> = self.generated_fields.iter()
+            .filter_map(|field| field.configuration.expression())
+            .flat_map(|expression| collect_idents(expression.clone()))
+            .collect();
+        for (param, argument) in &substitutions {
+            match param {
+                GenericParam::Type(TypeParam { ident, .. }) if expression_idents.contains(ident) => {
+                    self.specialized_items.push(quote! { type #ident = #argument; });
+                }
+                GenericParam::Const(ConstParam { ident, ty, .. }) if expression_idents.contains(ident) => {
+                    self.specialized_items.push(quote! { const #ident: #ty = #argument; });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the `where` clause of the constructor definition into the generics of the type, used when
+    /// the constructor is generated as an implementation of `Default`
+    pub fn default_generics(&self, type_generics: &Generics) -> Result<Generics, Error> {
+        if let Some(param) = self.generics.params.first() {
+            return Err(Error::new(param.span(), GENERIC_DEFAULT_ERR_MSG));
+        }
+        if let Some(error_type) = &self.error_type {
+            return Err(Error::new(error_type.span(), FALLIBLE_DEFAULT_ERR_MSG));
+        }
+        let mut generics = type_generics.clone();
+        if let Some(where_clause) = &self.generics.where_clause {
+            generics.make_where_clause().predicates.extend(where_clause.predicates.iter().cloned());
+        }
+        Ok(generics)
+    }
+}
+
+impl ParameterField {
+    /// The ident of the parameter within the constructor signature
+    pub fn ident(&self) -> &Ident {
+        self.parameter_ident.as_ref().unwrap_or(&self.field_ident)
+    }
+}
+
+impl ToTokens for ParameterField {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.append_all(&self.cfg_attrs);
+        self.ident().to_tokens(tokens);
+        tokens.append(Punct::new(':', Alone));
+        self.field_type.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for ParameterBinding {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let cfg_attrs = &self.cfg_attrs;
+        let field_ident = &self.field_ident;
+        let parameter_ident = &self.parameter_ident;
+        tokens.extend(quote! { #(#cfg_attrs)* let #field_ident = #parameter_ident; });
+    }
+}
+
+impl ToTokens for GeneratedField {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let cfg_attrs = &self.cfg_attrs;
+        let ident = &self.field_ident;
+        let ty = &self.field_type;
+
+        // the type is annotated so that fields generated earlier can be used by expressions
+        let token_stream = quote! {
+            #(#cfg_attrs)* let #ident: #ty =
+        };
+
+        tokens.extend(token_stream);
+
+        tokens.extend(match &self.configuration {
+            FieldConfigProperty::Cloned => quote! { ::core::clone::Clone::clone(#ident) },
+            FieldConfigProperty::Default => quote! { ::core::default::Default::default() },
+            FieldConfigProperty::ConstDefault { runtime } => {
+                // a missing runtime crate is reported at the field rather than at the derive
+                let runtime = respan(runtime, self.span);
+                quote! { <#ty as #runtime::ConstDefault>::DEFAULT }
+            }
+            FieldConfigProperty::Expression { expression, .. } => strip_raw_markers(expression),
+            FieldConfigProperty::Into => quote! { ::core::convert::Into::into(#ident) },
+            FieldConfigProperty::Iter { conversion, .. } if matches!(ty, Type::Array(_)) => {
+                let iter = Ident::new("iter", Span::mixed_site());
+                let array = Ident::new("array", Span::mixed_site());
+                let items = match conversion {
+                    IterConversion::None => quote! { ::core::iter::IntoIterator::into_iter(#ident) },
+                    IterConversion::Into => quote! {
+                        ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(#ident), ::core::convert::Into::into)
+                    },
+                    IterConversion::TryInto => quote! {
+                        ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(#ident), ::core::convert::TryInto::try_into)
+                    },
+                };
+                match (&self.array_length_error, ty) {
+                    (Some(length_error), Type::Array(array_type)) => {
+                        fallible_array_tokens(&items, *conversion == IterConversion::TryInto, &array_type.elem, &array_type.len, length_error)
+                    }
+                    _ => quote! {{
+                        let mut #iter = #items;
+                        let #array = ::core::array::from_fn(|_| ::core::option::Option::expect(
+                            ::core::iter::Iterator::next(&mut #iter),
+                            "iterator yielded fewer items than the length of the array"));
+                        if ::core::option::Option::is_some(&::core::iter::Iterator::next(&mut #iter)) {
+                            ::core::panic!("iterator yielded more items than the length of the array");
+                        }
+                        #array
+                    }},
+                }
+            }
+            FieldConfigProperty::Iter { conversion: IterConversion::None, .. } => quote! {
+                ::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(#ident))
+            },
+            FieldConfigProperty::Iter { conversion: IterConversion::Into, .. } => quote! {
+                ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(#ident), ::core::convert::Into::into))
+            },
+            FieldConfigProperty::Iter { conversion: IterConversion::TryInto, .. } => quote! {
+                ::core::iter::Iterator::collect::<::core::result::Result<_, _>>(::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(#ident), ::core::convert::TryInto::try_into))?
+            },
+            FieldConfigProperty::Repeat { expression } => {
+                let expression = strip_raw_markers(expression);
+                let value = Ident::new("value", Span::mixed_site());
+                quote! {{
+                    let #value = #expression;
+                    ::core::array::from_fn(|_| ::core::clone::Clone::clone(&#value))
+                }}
+            }
+            FieldConfigProperty::FromFn { expression } => {
+                let expression = strip_raw_markers(expression);
+                quote! { ::core::array::from_fn(#expression) }
+            }
+        });
+
+        tokens.append(Punct::new(';', Alone))
+    }
+}
+
+/// Fills an array from an iterator within a fallible constructor, returning the length error (or the error of a
+/// `try_into` conversion) instead of panicking
+fn fallible_array_tokens(
+    items: &proc_macro2::TokenStream,
+    is_try_into: bool,
+    element_type: &Type,
+    length: &syn::Expr,
+    length_error: &Path,
+) -> proc_macro2::TokenStream {
+    let iter = Ident::new("iter", Span::mixed_site());
+    let slots = Ident::new("slots", Span::mixed_site());
+    let count = Ident::new("count", Span::mixed_site());
+    let error = Ident::new("error", Span::mixed_site());
+    let item = Ident::new("item", Span::mixed_site());
+
+    // the first conversion error is kept, and the remaining slots are left empty
+    let (error_declaration, next_item, error_check) = if is_try_into {
+        (quote! { let mut #error = ::core::option::Option::None; }, quote! {
+            match ::core::iter::Iterator::next(&mut #iter) {
+                _ if ::core::option::Option::is_some(&#error) => ::core::option::Option::None,
+                ::core::option::Option::Some(::core::result::Result::Ok(#item)) => ::core::option::Option::Some(#item),
+                ::core::option::Option::Some(::core::result::Result::Err(#item)) => {
+                    #error = ::core::option::Option::Some(#item);
+                    ::core::option::Option::None
+                }
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        }, quote! {
+            if let ::core::option::Option::Some(#error) = #error {
+                return ::core::result::Result::Err(::core::convert::From::from(#error));
+            }
+        })
+    } else {
+        (quote! {}, quote! { ::core::iter::Iterator::next(&mut #iter) }, quote! {})
+    };
+
+    quote! {{
+        let mut #iter = #items;
+        let mut #count = 0usize;
+        #error_declaration
+        let #slots: [::core::option::Option<#element_type>; #length] = ::core::array::from_fn(|_| {
+            let #item = #next_item;
+            if ::core::option::Option::is_some(&#item) {
+                #count += 1;
+            }
+            #item
+        });
+        #error_check
+        if #count < #length {
+            return ::core::result::Result::Err(::core::convert::From::from(
+                #length_error::TooFew { expected: #length, found: #count }));
+        }
+        if ::core::option::Option::is_some(&::core::iter::Iterator::next(&mut #iter)) {
+            return ::core::result::Result::Err(::core::convert::From::from(#length_error::TooMany { expected: #length }));
+        }
+        let mut #slots = ::core::iter::IntoIterator::into_iter(#slots);
+        ::core::array::from_fn(|_| ::core::option::Option::expect(
+            ::core::option::Option::flatten(::core::iter::Iterator::next(&mut #slots)),
+            "every slot was filled"))
+    }}
+}
+
+fn try_parse_field_attributes(attributes: &[Attribute]) -> Result<Option<FieldConfig>, Error> {
+    for attribute in attributes {
+        let attr_path = attribute.path();
+        if attr_path.is_ident(CTOR_WORD) {
+            return attribute.parse_args().map(Some);
+        }
+        #[cfg(feature = "shorthand")]
+        if let Some(configuration) = try_parse_shorthand_attribute(attribute)? {
+            return Ok(Some(configuration));
+        }
+    }
+    Ok(None)
+}
+
+/// Parses a shorthand attribute (ex: `#[iter(u8)]` or `#[default = [1]]`) as a configuration. Only the attributes
+/// registered by the derive are considered, so that attributes of other crates (ex: `#[serde(default)]`) are left alone.
+#[cfg(feature = "shorthand")]
+fn try_parse_shorthand_attribute(attribute: &Attribute) -> Result<Option<FieldConfig>, Error> {
+    if !SHORTHAND_ATTRIBUTES.iter().any(|name| attribute.path().is_ident(name)) {
+        return Ok(None);
+    }
+    parse2(attribute.meta.to_token_stream()).map(Some)
+}
+
+/// Warns about attributes of other crates which would be valid ctor properties (ex: `#[serde(default)]`), as they
+/// are likely intended to configure the constructor
+#[cfg(feature = "shorthand")]
+pub(crate) fn foreign_attribute_warnings<'a>(fields: impl IntoIterator<Item = &'a syn::Field>) -> proc_macro2::TokenStream {
+    let mut warnings = proc_macro2::TokenStream::new();
+    for attribute in fields.into_iter().flat_map(|field| &field.attrs) {
+        let syn::Meta::List(list) = &attribute.meta else {
+            continue;
+        };
+        let is_registered = list.path.is_ident(CTOR_WORD)
+            || SHORTHAND_ATTRIBUTES.iter().any(|name| list.path.is_ident(name));
+        if is_registered || parse2::<FieldConfigProperty>(list.tokens.clone()).is_err() {
+            continue;
+        }
+        let message = crate::constants::FOREIGN_ATTRIBUTE_WARN_MSG
+            .replace("{attr}", &attribute.to_token_stream().to_string())
+            .replace("{prop}", &list.tokens.to_string());
+        // proc macros cannot emit warnings directly, so the use of a deprecated item is reported instead
+        warnings.extend(quote::quote_spanned! {attribute.span()=>
+            const _: () = {
+                #[deprecated(note = #message)]
+                struct ForeignCtorAttribute;
+                let _ = ForeignCtorAttribute;
+            };
+        });
+    }
+    warnings
+}
+
+/// Infers the item type of a collection field for a bare `#[ctor(iter)]`, ex: `Vec<T>` -> `T`,
+/// `HashMap<K, V>` -> `(K, V)`, `String` -> `char`
+fn infer_iter_type(ty: &Type) -> Result<Type, Error> {
+    let inferred = match ty {
+        Type::Array(array) => Some((*array.elem).clone()),
+        Type::Path(path) => path.path.segments.last().and_then(|segment| {
+            let arguments: Vec<&Type> = match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => arguments.args.iter()
+                    .filter_map(|argument| match argument {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            match (segment.ident.to_string().as_str(), arguments.as_slice()) {
+                ("String", []) => Some(parse_quote! { char }),
+                ("Vec" | "VecDeque" | "LinkedList" | "BTreeSet" | "HashSet" | "BinaryHeap", [item, ..]) => {
+                    Some((*item).clone())
+                }
+                ("Box", [Type::Slice(slice)]) => Some((*slice.elem).clone()),
+                ("BTreeMap" | "HashMap", [key, value, ..]) => Some(parse_quote! { (#key, #value) }),
+                _ => None,
+            }
+        }),
+        _ => None,
+    };
+    inferred.ok_or_else(|| Error::new(ty.span(),
+        ITER_INFER_ERR_MSG.replace("{type}", &ty.to_token_stream().to_string())))
+}
+
+/// Whether the type is a primitive scalar, which can never be filled from an iterator
+fn is_scalar(ty: &Type) -> bool {
+    const SCALARS: &[&str] = &["bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize",
+        "u8", "u16", "u32", "u64", "u128", "usize"];
+    match ty {
+        Type::Path(path) => path.qself.is_none() && SCALARS.iter().any(|scalar| path.path.is_ident(scalar)),
+        _ => false,
+    }
+}
+
+/// Replaces each generic parameter (ex: `T`, `N`, or `'a`) with its argument wherever it is used as a type, const
+/// argument, or lifetime
+struct GenericSubstitution {
+    substitutions: Vec<(GenericParam, GenericArgument)>,
+    /// the trait bounds of each type parameter, which qualify its associated types, ex: `T::Item`
+    trait_bounds: Vec<(Ident, Vec<Path>)>,
+    diagnostics: Diagnostics,
+}
+
+impl GenericSubstitution {
+    fn new(type_generics: &Generics, arguments: &AngleBracketedGenericArguments) -> Self {
+        let trait_bounds = type_generics.type_params()
+            .map(|param| (param.ident.clone(), trait_bounds(type_generics, &param.ident)))
+            .collect();
+        Self {
+            substitutions: type_generics.params.iter().cloned().zip(arguments.args.iter().cloned()).collect(),
+            trait_bounds,
+            diagnostics: Diagnostics::default(),
+        }
+    }
+
+    /// Returns the substitutions, or the errors of the associated types which could not be qualified
+    fn finish(self) -> Result<Vec<(GenericParam, GenericArgument)>, Error> {
+        self.diagnostics.finish()?;
+        Ok(self.substitutions)
+    }
+
+    fn find_type(&self, ident: &Ident) -> Option<&Type> {
+        self.substitutions.iter().find_map(|(param, argument)| match (param, argument) {
+            (GenericParam::Type(param), GenericArgument::Type(argument)) if param.ident == *ident => Some(argument),
+            _ => None,
+        })
+    }
+
+    fn find_const(&self, ident: &Ident) -> Option<&GenericArgument> {
+        self.substitutions.iter().find_map(|(param, argument)| match param {
+            GenericParam::Const(param) if param.ident == *ident => Some(argument),
+            _ => None,
+        })
+    }
+}
+
+impl VisitMut for GenericSubstitution {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            let parameter = path.segments.first()
+                .filter(|segment| path.leading_colon.is_none() && segment.arguments.is_none());
+            if let Some(argument) = parameter.and_then(|segment| self.find_type(&segment.ident)).cloned() {
+                if path.segments.len() == 1 {
+                    *ty = argument;
+                    return;
+                }
+                // an associated type of the parameter is qualified by the only trait bound of the parameter,
+                // ex: `T::Item` -> `<IntoIter<u8> as Iterator>::Item`
+                let parameter = &path.segments[0].ident;
+                let bounds = self.trait_bounds.iter().find(|(ident, _)| ident == parameter).map(|(_, bounds)| bounds);
+                let Some([trait_path]) = bounds.map(Vec::as_slice) else {
+                    self.diagnostics.push(Error::new(path.span(), SPECIALIZED_ASSOC_ERR_MSG
+                        .replace("{type}", &path.to_token_stream().to_string())
+                        .replace("{param}", &parameter.to_string())));
+                    return;
+                };
+                let mut trait_path = trait_path.clone();
+                self.visit_path_mut(&mut trait_path);
+                let mut associated: Punctuated<PathSegment, Token![::]> = path.segments.iter().skip(1).cloned().collect();
+                for segment in &mut associated {
+                    self.visit_path_segment_mut(segment);
+                }
+                *ty = parse_quote! { <#argument as #trait_path>::#associated };
+                return;
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_generic_argument_mut(&mut self, argument: &mut GenericArgument) {
+        // a const parameter used as a generic argument is parsed as a type, ex: `N` within `Foo<N>`
+        if let GenericArgument::Type(Type::Path(TypePath { qself: None, path })) = argument {
+            if let Some(substitution) = path.get_ident().and_then(|ident| self.find_const(ident)) {
+                *argument = substitution.clone();
+                return;
+            }
+        }
+        visit_mut::visit_generic_argument_mut(self, argument);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        // a const parameter used within a type, ex: `N` within `[T; N]`
+        if let Expr::Path(ExprPath { qself: None, path, .. }) = expr {
+            if let Some(substitution) = path.get_ident().and_then(|ident| self.find_const(ident)) {
+                *expr = match substitution {
+                    GenericArgument::Const(argument) => argument.clone(),
+                    argument => Expr::Verbatim(argument.to_token_stream()),
+                };
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        let argument = self.substitutions.iter().find_map(|(param, argument)| match (param, argument) {
+            (GenericParam::Lifetime(param), GenericArgument::Lifetime(argument)) if param.lifetime == *lifetime => {
+                Some(argument)
+            }
+            _ => None,
+        });
+        if let Some(argument) = argument {
+            *lifetime = argument.clone();
+        }
+    }
+}
+
+/// The traits bounding the type parameter within the generics or their where clause, without their associated type
+/// constraints, ex: `Iterator` for `T: Iterator<Item = u8> + ?Sized`
+fn trait_bounds(generics: &Generics, ident: &Ident) -> Vec<Path> {
+    let declared = generics.type_params().filter(|param| param.ident == *ident).flat_map(|param| &param.bounds);
+    let predicates = generics.where_clause.iter().flat_map(|where_clause| &where_clause.predicates)
+        .filter_map(|predicate| match predicate {
+            WherePredicate::Type(predicate) if matches!(&predicate.bounded_ty,
+                Type::Path(TypePath { qself: None, path }) if path.is_ident(ident)) => Some(&predicate.bounds),
+            _ => None,
+        })
+        .flatten();
+    declared.chain(predicates).filter_map(|bound| match bound {
+        TypeParamBound::Trait(bound) if matches!(bound.modifier, TraitBoundModifier::None) => {
+            let mut path = bound.path.clone();
+            if let Some(PathArguments::AngleBracketed(arguments)) = path.segments.last_mut().map(|s| &mut s.arguments) {
+                arguments.args = arguments.args.iter()
+                    .filter(|argument| matches!(argument, GenericArgument::Lifetime(_) | GenericArgument::Type(_) | GenericArgument::Const(_)))
+                    .cloned()
+                    .collect();
+            }
+            if let Some(segment) = path.segments.last_mut() {
+                if matches!(&segment.arguments, PathArguments::AngleBracketed(arguments) if arguments.args.is_empty()) {
+                    segment.arguments = PathArguments::None;
+                }
+            }
+            Some(path)
+        }
+        _ => None,
+    }).collect()
+}
+
+/// Parses a list of bounds separated by `+`
+struct TypeImplTraitBounds(Punctuated<TypeParamBound, Token![+]>);
+
+impl Parse for TypeImplTraitBounds {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(TypeImplTraitBounds(Punctuated::parse_terminated(input)?))
+    }
+}
+
+/// Creates a deterministic generic ident for a parameter (ex: `items` -> `TItems`) which does not collide with
+/// any of the reserved idents
+fn generic_parameter_ident(parameter: &Ident, reserved: &[Ident]) -> Ident {
+    let name = parameter.to_string();
+    let mut generic_name = "T".to_string();
+    for segment in name.trim_start_matches("r#").split('_').filter(|s| !s.is_empty()) {
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            generic_name.extend(first.to_uppercase());
+            generic_name.push_str(chars.as_str());
+        }
+    }
+    while reserved.iter().any(|ident| *ident == generic_name) {
+        generic_name.push('_');
+    }
+    Ident::new(&generic_name, Span::call_site())
+}
+
+/// Sets the span of every token of the path, so that errors within it are reported at the given location
+fn respan(path: &Path, span: Span) -> proc_macro2::TokenStream {
+    path.to_token_stream().into_iter().map(|mut token| {
+        token.set_span(span);
+        token
+    }).collect()
+}
+
+fn collect_idents(stream: proc_macro2::TokenStream) -> Vec<Ident> {
+    stream.into_iter().flat_map(|token| match token {
+        TokenTree::Ident(ident) => vec![ident],
+        TokenTree::Group(group) => collect_idents(group.stream()),
+        _ => Vec::new(),
+    }).collect()
+}
+
+/// The idents of the type and const parameters of the generics, ex: `T` and `N` within `<'a, T, const N: usize>`
+fn generic_param_idents(generics: &Generics) -> impl Iterator<Item = Ident> + '_ {
+    generics.params.iter().filter_map(|param| match param {
+        GenericParam::Type(param) => Some(param.ident.clone()),
+        GenericParam::Const(param) => Some(param.ident.clone()),
+        GenericParam::Lifetime(_) => None,
+    })
+}
+
+/// Reorders the parameters, first placing the parameters of fields annotated with `#[ctor(order = N)]` at their
+/// requested positions, then moving any parameters named by the definition's `order(...)` to the front.
+fn order_parameters(
+    parameters: Vec<ParameterField>,
+    ordered_parameters: Vec<(LitInt, Range<usize>)>,
+    definition_order: &[Ident],
+) -> Result<Vec<ParameterField>, Error> {
+    let mut slots: Vec<Option<ParameterField>> = parameters.into_iter().map(Some).collect();
+    let mut positioned: Vec<Option<ParameterField>> = vec![None; slots.len()];
+
+    for (order, range) in ordered_parameters {
+        let position = order.base10_parse::<usize>()?;
+        let target = position..position + range.len();
+        if target.end > positioned.len() || positioned[target.clone()].iter().any(Option::is_some) {
+            return Err(Error::new(order.span(), ORDER_PARAM_ERR_MSG.replace("{order}", &position.to_string())));
+        }
+        for (source, destination) in range.zip(target) {
+            positioned[destination] = slots[source].take();
+        }
+    }
+
+    let mut remaining = slots.into_iter().flatten();
+    let mut parameters: Vec<ParameterField> = positioned.into_iter()
+        .filter_map(|slot| slot.or_else(|| remaining.next()))
+        .collect();
+
+    for (position, ident) in definition_order.iter().enumerate() {
+        let index = parameters.iter().position(|p| p.ident() == ident).ok_or_else(|| {
+            Error::new(ident.span(), UNKNOWN_PARAM_ERR_MSG.replace("{param}", &ident.to_string())
+                + &suggestion(&ident.to_string(), parameters.iter().map(|p| p.ident().to_string())))
+        })?;
+        if index < position {
+            return Err(Error::new(ident.span(), DUPLICATE_PARAM_ERR_MSG.replace("{param}", &ident.to_string())));
+        }
+        let parameter = parameters.remove(index);
+        parameters.insert(position, parameter);
+    }
+
+    Ok(parameters)
+}
+
+/// Resolves the parameters and generated fields of the constructor at `ctor_index` of the item declaring `generics`,
+/// where `runtime` is the path of the crate declaring `ConstDefault`, used by the `default` fields of const
+/// constructors
+pub fn generate_ctor_meta(
+    definitions: &[CtorDefinition],
+    ctor_index: usize,
+    fields: &Fields,
+    generics: &Generics,
+    markers: &[Type],
+    runtime: &Path,
+) -> Result<ConstructorMeta, Error> {
+    let ctor_attributes = &definitions[ctor_index].attrs;
+    let mut meta = ConstructorMeta {
+        generics: definitions[ctor_index].generics.clone(),
+        error_type: definitions[ctor_index].error_type.clone(),
+        ..Default::default()
+    };
+    let mut ordered_parameters = Vec::new();
+    let mut diagnostics = Diagnostics::default();
+    // generated generic parameters must not collide with the idents of the field types, the generics of the item and
+    // the definition, or any other generated generic parameter
+    let mut reserved: Vec<Ident> = fields.iter().flat_map(|field| collect_idents(field.ty.to_token_stream()))
+        .chain(generic_param_idents(generics))
+        .chain(generic_param_idents(&definitions[ctor_index].generics))
+        .collect();
+
+    for (field_index, field) in fields.iter().enumerate() {
+        let Some(configuration) = diagnostics.check(try_parse_field_attributes(&field.attrs)) else {
+            continue;
+        };
+
+        let span = field.span();
+
+        let field_ident = field.ident.clone().unwrap_or_else(|| {
+            Ident::new(
+                &("arg".to_string() + &field_index.to_string()),
+                // call-site hygiene allows field expressions to reference the parameter, ex: expr(arg0 * 2)
+                Span::call_site(),
+            )
+        });
+
+        let cfg_attrs = cfg_attributes(&field.attrs);
+        meta.field_idents.push(field_ident.clone());
+        meta.field_cfg_attrs.push(cfg_attrs.clone());
+
+        let field_ident = field_ident.clone();
+        let ft = &field.ty;
+
+        let mut req_field_type = None;
+        let mut gen_configuration = None;
+        let is_default_all = ctor_attributes.contains(&CtorAttribute::DefaultAll);
+        let is_into_all = ctor_attributes.contains(&CtorAttribute::IntoAll);
+
+        let property = configuration.as_ref().and_then(|c| c.property.as_ref());
+        let parameter_start = meta.parameter_fields.len();
+
+        match property {
+            None if is_default_all || is_marker_type(ft, markers) => {
+                gen_configuration = Some(FieldConfigProperty::Default)
+            }
+            None if is_into_all => {
+                req_field_type = Some(parse2(quote! { impl ::core::convert::Into<#ft> })?);
+                gen_configuration = Some(FieldConfigProperty::Into)
+            }
+            None => req_field_type = Some(field.ty.clone()),
+            // default(all) should generate a property if the property is a non-generated one
+            Some(property) if !property.is_generated() && is_default_all => {
+                gen_configuration = Some(FieldConfigProperty::Default)
+            }
+            Some(property) => {
+                gen_configuration = Some(property.clone());
+
+                let applies = match &configuration {
+                    Some(configuration) => match diagnostics.check(configuration.applies_to(definitions, ctor_index)) {
+                        Some(applies) => applies,
+                        None => continue,
+                    },
+                    None => true,
+                };
+
+                if applies {
+                    // create a required field type if the configuration requires an additional input parameter
+                    req_field_type = match property {
+                        FieldConfigProperty::Cloned => Some(parse2(quote! { &#ft })?),
+                        FieldConfigProperty::Into => Some(parse2(quote! { impl ::core::convert::Into<#ft> })?),
+                        FieldConfigProperty::Iter { iter_type, conversion } => {
+                            if is_scalar(ft) {
+                                diagnostics.push(Error::new(span, ITER_SCALAR_ERR_MSG
+                                    .replace("{type}", &ft.to_token_stream().to_string())));
+                                continue;
+                            }
+                            let iter_type = match iter_type {
+                                Some(iter_type) => iter_type.clone(),
+                                None => match diagnostics.check(infer_iter_type(ft)) {
+                                    Some(iter_type) => iter_type,
+                                    None => continue,
+                                },
+                            };
+                            let item_type = match conversion {
+                                IterConversion::None => iter_type.to_token_stream(),
+                                // `impl Trait` is not allowed within the bounds of a named generic parameter
+                                IterConversion::Into if ctor_attributes.contains(&CtorAttribute::NamedGenerics) => {
+                                    let item_ident = Ident::new(&(field_ident.to_string() + "_item"), field_ident.span());
+                                    let ident = generic_parameter_ident(&item_ident, &reserved);
+                                    reserved.push(ident.clone());
+                                    meta.generic_parameters.push(GenericParameter {
+                                        ident: ident.clone(),
+                                        bounds: parse_quote! { ::core::convert::Into<#iter_type> },
+                                    });
+                                    ident.to_token_stream()
+                                }
+                                IterConversion::Into => quote! { impl ::core::convert::Into<#iter_type> },
+                                IterConversion::TryInto => {
+                                    // the item type must be named so that its error type can be returned
+                                    let item_ident = Ident::new(&(field_ident.to_string() + "_item"), field_ident.span());
+                                    let ident = generic_parameter_ident(&item_ident, &reserved);
+                                    reserved.push(ident.clone());
+                                    meta.generic_parameters.push(GenericParameter {
+                                        ident: ident.clone(),
+                                        bounds: parse_quote! { ::core::convert::TryInto<#iter_type> },
+                                    });
+                                    meta.fallible_errors.push((
+                                        parse_quote! { <#ident as ::core::convert::TryInto<#iter_type>>::Error },
+                                        span,
+                                    ));
+                                    ident.to_token_stream()
+                                }
+                            };
+                            Some(parse2(quote! { impl ::core::iter::IntoIterator<Item=#item_type> })?)
+                        }
+                        FieldConfigProperty::Expression { inputs, self_referencing, .. } => {
+                            let mut field_input = None;
+                            for input in inputs {
+                                match &input.ident {
+                                    Some(ident) => meta.parameter_fields.push(ParameterField {
+                                        field_ident: ident.clone(),
+                                        parameter_ident: None,
+                                        field_type: input.input_type.clone(),
+                                        span: ident.span(),
+                                        cfg_attrs: cfg_attrs.clone(),
+                                    }),
+                                    None => field_input = Some(input.input_type.clone()),
+                                }
+                            }
+                            match field_input {
+                                None if *self_referencing => Some(field.ty.clone()),
+                                field_input => field_input,
+                            }
+                        }
+                        _ => None,
+                    }
+                } else if is_marker_type(ft, markers) {
+                    gen_configuration = Some(FieldConfigProperty::Default);
+                } else {
+                    gen_configuration = None;
+                    req_field_type = Some(field.ty.clone());
+                }
+            }
+        }
+
+        if let Some(cfg) = gen_configuration {
+            meta.generated_fields.push(GeneratedField {
+                field_ident: field_ident.clone(),
+                field_type: field.ty.clone(),
+                configuration: cfg,
+                span,
+                cfg_attrs: cfg_attrs.clone(),
+                array_length_error: None,
+            })
+        }
+        if let Some(field_type) = req_field_type {
+            let parameter_ident = configuration.as_ref().and_then(|c| c.parameter_name.clone());
+            if let Some(parameter_ident) = &parameter_ident {
+                meta.parameter_bindings.push(ParameterBinding {
+                    field_ident: field_ident.clone(),
+                    parameter_ident: parameter_ident.clone(),
+                    cfg_attrs: cfg_attrs.clone(),
+                });
+            }
+            meta.parameter_fields.push(ParameterField {
+                field_ident,
+                parameter_ident,
+                field_type,
+                span,
+                cfg_attrs,
+            })
+        }
+
+        let order = configuration.as_ref().and_then(|c| c.parameter_order.as_ref());
+        if let Some(order) = order {
+            if meta.parameter_fields.len() > parameter_start {
+                ordered_parameters.push((order.clone(), parameter_start..meta.parameter_fields.len()));
+            }
+        }
+    }
+
+    let definition = &definitions[ctor_index];
+    for parameter in &definition.parameters {
+        if meta.field_idents.contains(&parameter.ident) {
+            diagnostics.push(Error::new(parameter.ident.span(),
+                DUPLICATE_PARAM_ERR_MSG.replace("{param}", &parameter.ident.to_string())));
+            continue;
+        }
+        meta.parameter_fields.push(ParameterField {
+            field_ident: parameter.ident.clone(),
+            parameter_ident: None,
+            field_type: parameter.parameter_type.clone(),
+            span: parameter.ident.span(),
+            cfg_attrs: Vec::new(),
+        });
+    }
+
+    for (i, parameter) in meta.parameter_fields.iter().enumerate() {
+        if meta.parameter_fields[..i].iter().any(|p| p.ident() == parameter.ident()) {
+            diagnostics.push(Error::new(parameter.span,
+                DUPLICATE_PARAM_ERR_MSG.replace("{param}", &parameter.ident().to_string())));
+        }
+    }
+
+    // the remaining steps require every field to have been resolved
+    diagnostics.finish()?;
+    let mut diagnostics = Diagnostics::default();
+
+    meta.parameter_fields = order_parameters(meta.parameter_fields, ordered_parameters, &definition.parameter_order)?;
+
+    if ctor_attributes.contains(&CtorAttribute::NamedGenerics) {
+        for parameter in &mut meta.parameter_fields {
+            if let Type::ImplTrait(impl_trait) = &parameter.field_type {
+                let ident = generic_parameter_ident(parameter.ident(), &reserved);
+                reserved.push(ident.clone());
+                meta.generic_parameters.push(GenericParameter { ident: ident.clone(), bounds: impl_trait.bounds.clone() });
+                parameter.field_type = parse2(quote! { #ident })?;
+            }
+        }
+    }
+
+    // Default::default() cannot be called within a const fn, so the const-evaluable default is used instead
+    if ctor_attributes.contains(&CtorAttribute::Const) {
+        for field in &mut meta.generated_fields {
+            if let FieldConfigProperty::Default = field.configuration {
+                field.configuration = FieldConfigProperty::ConstDefault { runtime: runtime.clone() };
+            }
+        }
+    }
+
+    // within a fallible constructor, an array filled by an iterator returns an error on a length mismatch
+    if meta.is_fallible() {
+        let length_error: Path = parse_quote! { #runtime::ArrayLengthError };
+        let mut array_span = None;
+        for field in &mut meta.generated_fields {
+            if let (FieldConfigProperty::Iter { .. }, Type::Array(_)) = (&field.configuration, &field.field_type) {
+                field.array_length_error = Some(length_error.clone());
+                array_span.get_or_insert(field.span);
+            }
+        }
+        if let Some(span) = array_span {
+            meta.fallible_errors.push((parse_quote! { #length_error }, span));
+        }
+    }
+
+    if let Some(generated_fields) = diagnostics.check(order_generated_fields(meta.generated_fields.clone(), &meta.parameter_fields)) {
+        meta.generated_fields = generated_fields;
+    }
+    diagnostics.check(check_unused_parameters(&definition.parameters, &meta.generated_fields));
+    diagnostics.finish()?;
+    Ok(meta)
+}
+
+#[test]
+fn test_generic_parameter_ident() {
+    let ident = |name: &str| Ident::new(name, Span::call_site());
+    assert_eq!(ident("TName"), generic_parameter_ident(&ident("name"), &[]));
+    assert_eq!(ident("TFieldName"), generic_parameter_ident(&ident("field_name"), &[]));
+    assert_eq!(ident("TArg0"), generic_parameter_ident(&ident("arg0"), &[]));
+    assert_eq!(ident("TItems_"), generic_parameter_ident(&ident("items"), &[ident("TItems")]));
+}
+
+#[test]
+fn test_generic_substitution() {
+    let generics: Generics = parse_quote!(<'a, T: Iterator<Item = u8>, U, const N: usize>);
+    let arguments: AngleBracketedGenericArguments = parse_quote!(<'static, IntoIter<u8>, u16, 4>);
+    let mut substitution = GenericSubstitution::new(&generics, &arguments);
+    let mut ty: Type = parse_quote!((&'a T, [T::Item; N], Wrapper<N, U>, other::T));
+    substitution.visit_type_mut(&mut ty);
+    let expected: Type = parse_quote!((&'static IntoIter<u8>, [<IntoIter<u8> as Iterator>::Item; 4], Wrapper<4, u16>, other::T));
+    assert_eq!(expected.to_token_stream().to_string(), ty.to_token_stream().to_string());
+    assert!(substitution.finish().is_ok());
+}
+
+#[test]
+fn test_generic_substitution_of_unbounded_associated_type() {
+    let generics: Generics = parse_quote!(<T>);
+    let arguments: AngleBracketedGenericArguments = parse_quote!(<IntoIter<u8>>);
+    let mut substitution = GenericSubstitution::new(&generics, &arguments);
+    substitution.visit_type_mut(&mut parse_quote!(Option<T::Item>));
+    assert!(substitution.finish().is_err());
+}
+
+#[test]
+fn test_infer_iter_type() {
+    let infer = |ty: &str| infer_iter_type(&syn::parse_str::<Type>(ty).unwrap())
+        .map(|ty| ty.to_token_stream().to_string());
+    assert_eq!("u8", infer("Vec<u8>").unwrap());
+    assert_eq!("T", infer("std::collections::HashSet<T, S>").unwrap());
+    assert_eq!("(K , V)", infer("HashMap<K, V>").unwrap());
+    assert_eq!("char", infer("String").unwrap());
+    assert_eq!("u16", infer("Box<[u16]>").unwrap());
+    assert!(infer("Option<u8>").is_err());
+}
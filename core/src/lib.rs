@@ -0,0 +1,326 @@
+#![no_std]
+#![allow(dead_code)]
+//! The constructor generator behind [`derive-ctor`](https://docs.rs/derive-ctor), for procedural macros which want
+//! to reuse its attribute model or generate constructors themselves.
+//!
+//! [`derive_ctor`] expands a type exactly like `#[derive(ctor)]`, while [`expand`] accepts the package-wide
+//! [`Metadata`] explicitly, which keeps the output independent of the manifest being compiled. The configuration
+//! types implement [`syn::parse::Parse`] (ex: [`structs::CtorStructConfiguration`] and [`fields::FieldConfig`]), and
+//! [`fields::generate_ctor_meta`] resolves the parameters and generated values of a single constructor.
+//!
+//! ```
+//! use derive_ctor_core::{debug::pretty_print, expand, metadata::Metadata};
+//!
+//! let input = syn::parse_quote! {
+//!     struct MyStruct {
+//!         value: u32
+//!     }
+//! };
+//! let expansion = pretty_print(expand(input, &Metadata::default()));
+//! assert!(expansion.contains("pub fn new(value: u32) -> Self"));
+//! ```
+
+extern crate alloc;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::collections::BTreeSet as HashSet;
+
+use crate::constants::{CFG_WORD, CTOR_WORD, VISIBILITY_VALUE_INHERIT};
+#[cfg(feature = "enums")]
+use crate::enums::create_enum_token_stream;
+#[cfg(feature = "structs")]
+use crate::structs::create_struct_token_stream;
+#[cfg(feature = "unions")]
+use crate::unions::create_union_token_stream;
+use crate::metadata::Metadata;
+
+use proc_macro2::{Delimiter, Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::discouraged::AnyDelimiter;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::{AngleBracketedGenericArguments, GenericArgument, Generics, Path, PathArguments, Visibility};
+use syn::spanned::Spanned;
+use syn::Attribute;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Error;
+use syn::token::Pub;
+use syn::Type;
+
+
+pub mod constants;
+pub mod debug;
+pub(crate) mod diagnostics;
+#[cfg(feature = "enums")]
+pub mod enums;
+#[cfg(any(feature = "enums", feature = "structs", feature = "unions"))]
+pub(crate) mod expressions;
+#[cfg(any(feature = "enums", feature = "structs", feature = "unions"))]
+pub mod fields;
+pub mod metadata;
+#[cfg(feature = "structs")]
+pub mod structs;
+#[cfg(feature = "unions")]
+pub mod unions;
+#[cfg(any(feature = "enums", feature = "structs", feature = "unions"))]
+pub(crate) mod validation;
+
+pub struct CtorDefinition {
+    pub visibility: Visibility,
+    pub ident: Ident,
+    pub attrs: HashSet<CtorAttribute>,
+    pub parameters: Vec<CtorParameter>,
+    pub parameter_order: Vec<Ident>,
+    pub generics: Generics,
+    /// concrete generic arguments of the type the constructor is exclusively generated for, ex: `for <u8, 4096>`
+    pub specialization: Option<AngleBracketedGenericArguments>,
+    /// the error type of a fallible constructor, ex: `new(error = ParseError)`
+    pub error_type: Option<Type>,
+}
+
+/// An additional parameter declared by a constructor definition which is not itself a field,
+/// ex: `#[ctor(new(scale: f32))]`
+pub struct CtorParameter {
+    pub ident: Ident,
+    pub parameter_type: Type,
+}
+
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CtorAttribute {
+    Const,
+    DefaultAll,
+    Default,
+    IntoAll,
+    NamedGenerics,
+}
+
+/// The visibility of the constructors which do not declare their own, ex: `vis = pub(crate)` or `vis = inherit`
+#[derive(Clone)]
+pub enum DefaultVisibility {
+    Visibility(Visibility),
+    /// matches the visibility of the type itself
+    Inherit,
+}
+
+impl DefaultVisibility {
+    pub fn resolve(&self, item_visibility: &Visibility) -> Visibility {
+        match self {
+            DefaultVisibility::Visibility(visibility) => visibility.clone(),
+            DefaultVisibility::Inherit => item_visibility.clone(),
+        }
+    }
+}
+
+impl Parse for DefaultVisibility {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        if fork.parse::<Ident>().is_ok_and(|ident| ident == VISIBILITY_VALUE_INHERIT) {
+            input.parse::<Ident>()?;
+            return Ok(DefaultVisibility::Inherit);
+        }
+        Ok(DefaultVisibility::Visibility(input.parse()?))
+    }
+}
+
+impl Default for CtorDefinition {
+    fn default() -> Self {
+        Self {
+            visibility: Visibility::Public(Pub {
+                span: Span::call_site(),
+            }),
+            ident: Ident::new("new", Span::mixed_site()),
+            attrs: Default::default(),
+            parameters: Vec::new(),
+            parameter_order: Vec::new(),
+            generics: Generics::default(),
+            specialization: None,
+            error_type: None,
+        }
+    }
+}
+
+#[cfg(not(feature = "enums"))]
+pub(crate) fn create_enum_token_stream(_derive_input: DeriveInput, _metadata: &Metadata) -> TokenStream {
+    use proc_macro2::Span;
+    Error::new(Span::call_site(),
+        "\"enums\" feature must be enabled to use #[derive(ctor)] on enums.").to_compile_error()
+}
+
+#[cfg(not(feature = "structs"))]
+pub(crate) fn create_struct_token_stream(_derive_input: DeriveInput, _metadata: &Metadata) -> TokenStream {
+    Error::new(Span::call_site(),
+        "\"structs\" feature must be enabled to use #[derive(ctor)] on structs.").to_compile_error()
+}
+
+#[cfg(not(feature = "unions"))]
+pub(crate) fn create_union_token_stream(_derive_input: DeriveInput, _metadata: &Metadata) -> TokenStream {
+    Error::new(Span::call_site(),
+        "\"unions\" feature must be enabled to use #[derive(ctor)] on unions.").to_compile_error()
+}
+
+/// Expands the constructors of a struct, enum or union like `#[derive(ctor)]`, using the metadata of the package
+/// being compiled (see [`Metadata::load`]). Errors are returned as `compile_error!` invocations.
+pub fn derive_ctor(input: TokenStream) -> TokenStream {
+    let derive_input = match syn::parse2::<DeriveInput>(input) {
+        Ok(derive_input) => derive_input,
+        Err(err) => return err.to_compile_error(),
+    };
+    match Metadata::load() {
+        Ok(metadata) => expand(derive_input, &metadata),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+/// Expands the constructors of a struct, enum or union with the given package-wide defaults
+pub fn expand(derive_input: DeriveInput, metadata: &Metadata) -> TokenStream {
+    match &derive_input.data {
+        Data::Struct(_) => create_struct_token_stream(derive_input, metadata),
+        Data::Enum(_) => create_enum_token_stream(derive_input, metadata),
+        Data::Union(_) => create_union_token_stream(derive_input, metadata)
+    }
+}
+
+pub(crate) fn try_parse_attributes_with_default<T: Parse, F: Fn() -> T>(
+    attributes: &[Attribute],
+    default: F,
+) -> Result<T, Error> {
+    for attribute in attributes {
+        if attribute.path().is_ident(CTOR_WORD) {
+            return attribute.parse_args::<T>();
+        }
+    }
+    Ok(default())
+}
+
+pub(crate) fn try_parse_attributes<T: Parse>(attributes: &[Attribute]) -> Result<Option<T>, Error> {
+    for attribute in attributes {
+        if attribute.path().is_ident(CTOR_WORD) {
+            return attribute.parse_args::<T>().map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// Determines whether the type is a zero-sized marker which is generated instead of being passed as a parameter.
+/// Markers are `PhantomData` and `PhantomPinned` (either imported or qualified by `core::marker` or `std::marker`),
+/// the unit type, and the types declared by `markers(...)`, where `_` matches any generic argument.
+pub fn is_marker_type(ty: &Type, markers: &[Type]) -> bool {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Paren(paren) => is_marker_type(&paren.elem, markers),
+        Type::Group(group) => is_marker_type(&group.elem, markers),
+        Type::Path(path) if path.qself.is_none() => {
+            is_std_marker(&path.path) || markers.iter().any(|marker| matches_marker(ty, marker))
+        }
+        _ => false,
+    }
+}
+
+fn is_std_marker(path: &Path) -> bool {
+    let segments: Vec<String> = path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+    match segments.iter().map(String::as_str).collect::<Vec<&str>>().as_slice() {
+        [prefix @ .., "PhantomData" | "PhantomPinned"] => matches!(prefix, [] | ["core" | "std", "marker"]),
+        _ => false,
+    }
+}
+
+fn matches_marker(ty: &Type, marker: &Type) -> bool {
+    match (ty, marker) {
+        (_, Type::Infer(_)) => true,
+        (Type::Path(ty), Type::Path(marker)) if ty.qself.is_none() && marker.qself.is_none() => {
+            let ty_segments = &ty.path.segments;
+            let marker_segments = &marker.path.segments;
+            // a marker may be declared by its name alone, ex: `Tag<_>` matches `crate::Tag<u8>`
+            marker_segments.len() <= ty_segments.len() && ty_segments.iter().skip(ty_segments.len() - marker_segments.len())
+                .zip(marker_segments)
+                .all(|(ty_segment, marker_segment)| {
+                    ty_segment.ident == marker_segment.ident
+                        && matches_arguments(&ty_segment.arguments, &marker_segment.arguments)
+                })
+        }
+        _ => ty.to_token_stream().to_string() == marker.to_token_stream().to_string(),
+    }
+}
+
+fn matches_arguments(ty_arguments: &PathArguments, marker_arguments: &PathArguments) -> bool {
+    match (ty_arguments, marker_arguments) {
+        (PathArguments::None, PathArguments::None) => true,
+        (PathArguments::AngleBracketed(ty_arguments), PathArguments::AngleBracketed(marker_arguments)) => {
+            ty_arguments.args.len() == marker_arguments.args.len()
+                && ty_arguments.args.iter().zip(&marker_arguments.args).all(|arguments| match arguments {
+                    (GenericArgument::Type(ty), GenericArgument::Type(marker)) => matches_marker(ty, marker),
+                    (argument, GenericArgument::Type(Type::Infer(_))) => !matches!(argument, GenericArgument::Lifetime(_)),
+                    (argument, marker) => argument.to_token_stream().to_string() == marker.to_token_stream().to_string(),
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Collects the `#[cfg(...)]` attributes of a field, variant or union field so that they can be carried onto the
+/// code generated for it. `#[cfg_attr(...)]` is not collected, as it is expanded before the derive receives its input.
+pub(crate) fn cfg_attributes(attributes: &[Attribute]) -> Vec<Attribute> {
+    attributes.iter().filter(|attribute| attribute.path().is_ident(CFG_WORD)).cloned().collect()
+}
+
+pub(crate) fn consume_delimited<T, F>(
+    stream: ParseStream,
+    expected: Delimiter,
+    expression: F,
+) -> Result<T, Error>
+where
+    F: Fn(ParseStream) -> Result<T, Error>,
+{
+    let (delimiter, span, buffer) = stream.parse_any_delimiter()?;
+    if delimiter != expected {
+        return Err(Error::new(span.span(),
+            format!("Expected enclosing {:?}", expected),
+        ));
+    }
+    expression(&buffer)
+}
+
+pub(crate) fn adjust_keyword_ident(name: String) -> String {
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        return name;
+    }
+    format!("r#{}", name)
+}
+
+/// The attributes of every generated impl block, which allow the lints triggered by constructors with
+/// many parameters or a parameterless `new` to be reported against the user's own code instead
+pub(crate) fn generated_impl_attributes() -> proc_macro2::TokenStream {
+    quote! {
+        #[automatically_derived]
+        #[allow(clippy::too_many_arguments, clippy::new_without_default)]
+    }
+}
+
+#[test]
+fn test_is_marker_type() {
+    let is_marker = |ty: &str| is_marker_type(&syn::parse_str::<Type>(ty).unwrap(),
+        &[syn::parse_str("Tag<_>").unwrap(), syn::parse_str("Empty").unwrap()]);
+    assert!(is_marker("PhantomData"));
+    assert!(is_marker("PhantomData<&'static str>"));
+    assert!(is_marker("::core::marker::PhantomData<T>"));
+    assert!(is_marker("std::marker::PhantomPinned"));
+    assert!(is_marker("()"));
+    assert!(is_marker("Tag<u8>"));
+    assert!(is_marker("crate::Tag<Vec<u8>>"));
+    assert!(is_marker("Empty"));
+    assert!(!is_marker("i32"));
+    assert!(!is_marker("Vec<PhantomData<u8>>"));
+    assert!(!is_marker("my::PhantomData"));
+    assert!(!is_marker("Tag"));
+    assert!(!is_marker("Empty<u8>"));
+}
+
+#[test]
+fn test_adjust_keyword_ident() {
+    assert_eq!("abc".to_string(), adjust_keyword_ident("abc".to_string()));
+    assert_eq!("r#break".to_string(), adjust_keyword_ident("break".to_string()));
+    assert_eq!("r#fn".to_string(), adjust_keyword_ident("fn".to_string()));
+    assert_eq!("r#const".to_string(), adjust_keyword_ident("const".to_string()));
+}
\ No newline at end of file
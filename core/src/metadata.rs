@@ -0,0 +1,321 @@
+extern crate alloc;
+extern crate std;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_quote, Error, Ident, Meta};
+
+use crate::constants::{METADATA_ATTRIBUTES as ATTRIBUTES, METADATA_ERR_MSG, METADATA_NAME as NAME, METADATA_NAMING as NAMING, METADATA_PACKAGE_TABLE, METADATA_PREFIX as PREFIX, METADATA_RUNTIME as RUNTIME, METADATA_SHORTHAND_WARNINGS as SHORTHAND_WARNINGS, METADATA_VIS as VIS, METADATA_WORKSPACE_TABLE, NAMING_VALUE_PRESERVE as PRESERVE, NAMING_VALUE_SNAKE_CASE as SNAKE_CASE};
+use crate::diagnostics::unknown_property_error;
+use crate::DefaultVisibility;
+
+const METADATA_KEYS: &[&str] = &[VIS, NAME, PREFIX, NAMING, ATTRIBUTES, SHORTHAND_WARNINGS, RUNTIME];
+const NAMING_VALUES: &[&str] = &[SNAKE_CASE, PRESERVE];
+
+/// The defaults shared by every derive within a package, read from `[package.metadata.derive-ctor]` and
+/// `[workspace.metadata.derive-ctor]`. Values declared by the package take precedence over those of the workspace,
+/// and the configuration of each type takes precedence over both.
+///
+/// # Example
+///
+/// ```toml
+/// [package.metadata.derive-ctor]
+/// vis = "pub(crate)"
+/// name = "create"
+/// prefix = "new"
+/// naming = "snake_case"
+/// attributes = ["must_use"]
+/// shorthand-warnings = true
+/// runtime = "::my_crate::ctor_runtime"
+/// ```
+pub struct Metadata {
+    /// the visibility of the default constructor and of constructors declared by a bare name, ex: `vis = "inherit"`
+    pub visibility: Option<DefaultVisibility>,
+    /// the name of the constructor generated for a struct without a configuration
+    pub name: Option<Ident>,
+    /// the prefix of the constructors generated for enum variants and union fields
+    pub prefix: Option<Ident>,
+    pub naming: NamingStrategy,
+    /// the attributes added to every generated constructor, ex: `attributes = ["must_use", "inline"]`
+    pub attributes: Vec<Meta>,
+    /// whether attributes of other crates which look like ctor properties are reported
+    pub shorthand_warnings: bool,
+    /// the path of the crate declaring `ConstDefault`, ex: `runtime = "::my_crate::ctor_runtime"` for a re-export
+    pub runtime: syn::Path,
+}
+
+/// How the constructors of enum variants are named after their variant
+#[derive(Clone, Copy, PartialEq)]
+pub enum NamingStrategy {
+    /// `MyVariant` -> `my_variant`
+    SnakeCase,
+    /// `MyVariant` -> `MyVariant`
+    Preserve,
+}
+
+impl Default for Metadata {
+    fn default() -> Self {
+        Self {
+            visibility: None,
+            name: None,
+            prefix: None,
+            naming: NamingStrategy::SnakeCase,
+            attributes: Vec::new(),
+            shorthand_warnings: cfg!(feature = "shorthand-warnings"),
+            runtime: parse_quote!(::derive_ctor_runtime),
+        }
+    }
+}
+
+impl Metadata {
+    /// Reads the metadata of the package being compiled and of the workspace it belongs to, if any
+    pub fn load() -> Result<Self, Error> {
+        let mut metadata = Metadata::default();
+        let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from) else {
+            return Ok(metadata);
+        };
+
+        let package_manifest = read_manifest(&manifest_dir);
+        let workspace_manifest = manifest_dir.ancestors()
+            .filter_map(read_manifest)
+            .find(|manifest| read_table(manifest, "workspace").is_some());
+
+        if let Some(entries) = workspace_manifest.and_then(|manifest| read_table(&manifest, METADATA_WORKSPACE_TABLE)) {
+            metadata.apply(&entries)?;
+        }
+        if let Some(entries) = package_manifest.and_then(|manifest| read_table(&manifest, METADATA_PACKAGE_TABLE)) {
+            metadata.apply(&entries)?;
+        }
+        Ok(metadata)
+    }
+
+    /// Generates the attributes added to every generated constructor, ex: `#[must_use]`
+    pub(crate) fn method_attributes(&self) -> proc_macro2::TokenStream {
+        let attributes = &self.attributes;
+        quote! { #(#[#attributes])* }
+    }
+
+    fn apply(&mut self, entries: &[(String, Value)]) -> Result<(), Error> {
+        for (key, value) in entries {
+            let invalid = |expected: &str| Error::new(Span::call_site(), METADATA_ERR_MSG
+                .replace("{key}", key)
+                .replace("{expected}", expected));
+            match (key.as_str(), value) {
+                (VIS, Value::String(value)) => {
+                    self.visibility = Some(syn::parse_str(value).map_err(|_| invalid("a visibility or \"inherit\""))?);
+                }
+                (NAME, Value::String(value)) => {
+                    self.name = Some(syn::parse_str(value).map_err(|_| invalid("an identifier"))?);
+                }
+                (PREFIX, Value::String(value)) => {
+                    self.prefix = Some(syn::parse_str(value).map_err(|_| invalid("an identifier"))?);
+                }
+                (NAMING, Value::String(value)) => {
+                    self.naming = match value.as_str() {
+                        SNAKE_CASE => NamingStrategy::SnakeCase,
+                        PRESERVE => NamingStrategy::Preserve,
+                        _ => return Err(unknown_property_error(Span::call_site(), value, NAMING_VALUES)),
+                    };
+                }
+                (ATTRIBUTES, Value::Array(values)) => {
+                    self.attributes = values.iter()
+                        .map(|value| syn::parse_str(value).map_err(|_| invalid("an array of attributes")))
+                        .collect::<Result<_, _>>()?;
+                }
+                (SHORTHAND_WARNINGS, Value::Boolean(value)) => self.shorthand_warnings = *value,
+                (RUNTIME, Value::String(value)) => {
+                    self.runtime = syn::parse_str(value).map_err(|_| invalid("a path"))?;
+                }
+                (VIS | NAME | PREFIX | NAMING | RUNTIME, _) => return Err(invalid("a string")),
+                (ATTRIBUTES, _) => return Err(invalid("an array of strings")),
+                (SHORTHAND_WARNINGS, _) => return Err(invalid("a boolean")),
+                _ => return Err(unknown_property_error(Span::call_site(), key, METADATA_KEYS)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A value of the supported subset of TOML
+#[derive(Debug, PartialEq)]
+enum Value {
+    String(String),
+    Boolean(bool),
+    Array(Vec<String>),
+    /// any other value (ex: a number or an inline table), which is rejected if it belongs to a known key
+    Other,
+}
+
+fn read_manifest(directory: &Path) -> Option<String> {
+    std::fs::read_to_string(directory.join("Cargo.toml")).ok()
+}
+
+/// Reads the `key = value` entries of a table of the manifest (ex: `package.metadata.derive-ctor`), if it exists.
+/// Only strings, booleans and arrays of strings are supported, as they are the only values used by the metadata.
+fn read_table(manifest: &str, table: &str) -> Option<Vec<(String, Value)>> {
+    let mut entries = None;
+    let mut in_table = false;
+    let mut lines = manifest.lines();
+    while let Some(line) = lines.next() {
+        let line = strip_comment(line);
+        let line = line.trim();
+        if line.starts_with('[') {
+            let header: String = line.trim_start_matches('[').trim_end_matches(']')
+                .split('.').map(|segment| segment.trim().trim_matches('"')).collect::<Vec<&str>>().join(".");
+            in_table = header == table;
+            if in_table {
+                entries.get_or_insert_with(Vec::new);
+            }
+            continue;
+        }
+        if !in_table {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let mut value = value.trim().to_string();
+        // arrays may span several lines
+        if value.starts_with('[') {
+            while !value.ends_with(']') {
+                let Some(next) = lines.next() else {
+                    break;
+                };
+                value.push_str(strip_comment(next).trim());
+            }
+        }
+        entries.get_or_insert_with(Vec::new).push((key.trim().trim_matches('"').to_string(), parse_value(&value)));
+    }
+    entries
+}
+
+/// Removes a trailing `# comment` which is not within a string
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    line
+}
+
+fn parse_value(value: &str) -> Value {
+    match value {
+        "true" => Value::Boolean(true),
+        "false" => Value::Boolean(false),
+        _ if value.starts_with('[') && value.ends_with(']') => {
+            let items = &value[1..value.len() - 1];
+            let mut strings = Vec::new();
+            for item in split_array(items) {
+                match parse_value(item) {
+                    Value::String(string) => strings.push(string),
+                    _ => return Value::Other,
+                }
+            }
+            Value::Array(strings)
+        }
+        _ => parse_string(value).map_or(Value::Other, Value::String),
+    }
+}
+
+/// Splits the items of an array on the commas which are not within a string
+fn split_array(items: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in items.char_indices() {
+        match c {
+            '"' if !escaped => in_string = !in_string,
+            ',' if !in_string => {
+                split.push(items[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    split.push(items[start..].trim());
+    split.into_iter().filter(|item| !item.is_empty()).collect()
+}
+
+/// Parses a basic (`"..."`) or literal (`'...'`) string
+fn parse_string(value: &str) -> Option<String> {
+    if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        return Some(value[1..value.len() - 1].to_string());
+    }
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return None;
+    }
+    let mut string = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => string.push('\n'),
+                't' => string.push('\t'),
+                escaped => string.push(escaped),
+            },
+            c => string.push(c),
+        }
+    }
+    Some(string)
+}
+
+#[test]
+fn test_read_table() {
+    let manifest = r#"
+[package]
+name = "example"
+
+[package.metadata."derive-ctor"]
+vis = "pub(crate)" # constructors are crate-visible
+attributes = [
+    "must_use",
+    "doc = \"a, b\"",
+]
+shorthand-warnings = true
+order = 1
+
+[dependencies]
+vis = "pub"
+"#;
+    let entries = read_table(manifest, METADATA_PACKAGE_TABLE).unwrap();
+    assert_eq!(alloc::vec![
+        ("vis".to_string(), Value::String("pub(crate)".to_string())),
+        ("attributes".to_string(), Value::Array(alloc::vec!["must_use".to_string(), "doc = \"a, b\"".to_string()])),
+        ("shorthand-warnings".to_string(), Value::Boolean(true)),
+        ("order".to_string(), Value::Other),
+    ], entries);
+    assert!(read_table(manifest, METADATA_WORKSPACE_TABLE).is_none());
+}
+
+#[test]
+fn test_apply_metadata() {
+    let mut metadata = Metadata::default();
+    metadata.apply(&[
+        ("vis".to_string(), Value::String("inherit".to_string())),
+        ("prefix".to_string(), Value::String("new".to_string())),
+        ("naming".to_string(), Value::String("preserve".to_string())),
+        ("attributes".to_string(), Value::Array(alloc::vec!["must_use".to_string()])),
+        ("runtime".to_string(), Value::String("crate::runtime".to_string())),
+    ]).unwrap();
+    assert!(matches!(metadata.visibility, Some(DefaultVisibility::Inherit)));
+    assert_eq!("new", metadata.prefix.unwrap().to_string());
+    assert!(metadata.naming == NamingStrategy::Preserve);
+    assert_eq!(1, metadata.attributes.len());
+    assert_eq!("crate :: runtime", quote::ToTokens::to_token_stream(&metadata.runtime).to_string());
+
+    assert!(Metadata::default().apply(&[("name".to_string(), Value::Boolean(true))]).is_err());
+    assert!(Metadata::default().apply(&[("naming".to_string(), Value::String("camel".to_string()))]).is_err());
+    assert!(Metadata::default().apply(&[("visibility".to_string(), Value::String("pub".to_string()))]).is_err());
+}
@@ -0,0 +1,439 @@
+extern crate alloc;
+
+use alloc::collections::BTreeSet as HashSet;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use proc_macro2::TokenStream;
+
+use proc_macro2::Delimiter;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Generics, Ident, LitBool, Type, Visibility, WhereClause, WherePredicate};
+use syn::parse::{Parse, ParseStream};
+use syn::Token;
+use syn::spanned::Spanned;
+use syn::punctuated::Punctuated;
+use syn::token::{Comma, Const, Paren};
+
+use crate::{consume_delimited, CtorAttribute, CtorDefinition, CtorParameter, DefaultVisibility, generated_impl_attributes, try_parse_attributes_with_default};
+use crate::constants::{SPECIALIZED_DEFAULT_ERR_MSG, ENUM_VARIATION_PROP_NONE as NONE, NESTED_PROP_ALL as ALL, STRUCT_PROP_DEFAULT as DEFAULT, STRUCT_PROP_INTO as INTO, STRUCT_PROP_ORDER as ORDER, STRUCT_PROP_GENERICS as GENERICS, STRUCT_PROP_ERROR as ERROR, STRUCT_PROP_MARKERS as MARKERS, STRUCT_PROP_VIS as VIS, STRUCT_PROP_VISIBILITY as VISIBILITY, STRUCT_PROP_DEBUG as DEBUG, GENERICS_VALUE_NAMED as NAMED, GENERICS_VALUE_IMPL as IMPL};
+use crate::debug::debug_expansion;
+use crate::diagnostics::{unknown_property_error, Diagnostics};
+use crate::fields::generate_ctor_meta;
+use crate::metadata::Metadata;
+use crate::validation::Validator;
+
+const STRUCT_CTOR_PROPS: &[&str] = &[DEFAULT, INTO, ORDER, GENERICS, ERROR];
+const GENERICS_VALUES: &[&str] = &[NAMED, IMPL];
+
+#[derive(Default)]
+pub struct CtorStructConfiguration {
+    /// the constructors to generate, where the default constructor is added once the metadata is applied
+    pub definitions: Vec<CtorDefinition>,
+    pub is_none: bool,
+    /// the user-defined zero-sized types which are generated instead of being passed as parameters, ex: `markers(Tag<_>)`
+    pub markers: Vec<Type>,
+    /// the visibility of the definitions declared by a bare name, ex: `vis = inherit`
+    pub default_visibility: Option<DefaultVisibility>,
+    /// the indices of the definitions declared by a bare name, which take the default visibility
+    pub bare_definitions: Vec<usize>,
+    /// whether the generated code is reported as a compile error, ex: `#[ctor(debug = true)]`
+    pub debug: bool,
+}
+
+impl Parse for CtorStructConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut definitions = Vec::new();
+        let mut markers = Vec::new();
+        let mut default_visibility = None;
+        let mut bare_definitions = Vec::new();
+        let mut debug = false;
+
+        loop {
+            let mut attributes = HashSet::new();
+            if input.parse::<Const>().is_ok() {
+                attributes.insert(CtorAttribute::Const);
+            }
+
+            let definition = if !input.peek(syn::Ident) {
+                let visibility = input.parse()?;
+                // required to support both: VIS const and const VIS
+                if input.parse::<Const>().is_ok() {
+                    attributes.insert(CtorAttribute::Const);
+                }
+                let mut definition = CtorDefinition {
+                    visibility,
+                    ident: input.parse()?,
+                    attrs: attributes,
+                    parameters: Vec::new(),
+                    parameter_order: Vec::new(),
+                    generics: Default::default(),
+                    specialization: None,
+                    error_type: None,
+                };
+                parse_definition_signature(input, &mut definition)?;
+                definition
+            } else {
+                let ident = input.parse::<Ident>()?;
+
+                match ident.to_string().as_str() {
+                    // check for "none" as first parameter, if exists return early (this is only applicable for enums)
+                    NONE if definitions.is_empty() => {
+                        return Ok(CtorStructConfiguration {
+                            definitions: Default::default(),
+                            is_none: true,
+                            ..Default::default()
+                        })
+                    }
+                    VIS | VISIBILITY if input.peek(Token![=]) => {
+                        input.parse::<Token![=]>()?;
+                        default_visibility = Some(input.parse()?);
+                        if input.parse::<Comma>().is_err() {
+                            break;
+                        }
+                        continue;
+                    }
+                    // "debug = true" is a property, so that a bare "debug" still declares a constructor
+                    DEBUG if input.peek(Token![=]) => {
+                        input.parse::<Token![=]>()?;
+                        debug = input.parse::<LitBool>()?.value;
+                        if input.parse::<Comma>().is_err() {
+                            break;
+                        }
+                        continue;
+                    }
+                    MARKERS if input.peek(Paren) => {
+                        markers.extend(consume_delimited(input, Delimiter::Parenthesis, |types| {
+                            Punctuated::<Type, Comma>::parse_terminated(types)
+                        })?);
+                        if input.parse::<Comma>().is_err() {
+                            break;
+                        }
+                        continue;
+                    }
+                    DEFAULT => {
+                        attributes.insert(CtorAttribute::Default);
+                    }
+                    _ => {}
+                }
+
+                let mut definition = CtorDefinition {
+                    visibility: Visibility::Inherited,
+                    ident,
+                    attrs: attributes,
+                    parameters: Vec::new(),
+                    parameter_order: Vec::new(),
+                    generics: Default::default(),
+                    specialization: None,
+                    error_type: None,
+                };
+                parse_definition_signature(input, &mut definition)?;
+                bare_definitions.push(definitions.len());
+                definition
+            };
+
+            definitions.push(definition);
+
+            // Consume a comma to continue looking for constructors
+            if input.parse::<Comma>().is_err() {
+                break;
+            }
+        }
+
+        Ok(Self {
+            definitions,
+            is_none: false,
+            markers,
+            default_visibility,
+            bare_definitions,
+            debug,
+        })
+    }
+}
+
+impl CtorStructConfiguration {
+    /// Applies the package-wide defaults of the metadata which the configuration does not override, and the default
+    /// visibility to the definitions declared by a bare name, ex: `#[ctor(vis = inherit, new)]`. A configuration which
+    /// declares no definitions generates the one created by `default_definition` (ex: the variant-named constructor).
+    pub fn apply_metadata(
+        &mut self,
+        item_visibility: &Visibility,
+        metadata: &Metadata,
+        default_definition: impl FnOnce() -> CtorDefinition,
+    ) {
+        // a configuration which only declares markers or a visibility still generates the default constructor
+        if self.definitions.is_empty() && !self.is_none {
+            let mut definition = default_definition();
+            if let Some(default_visibility) = &self.default_visibility {
+                definition.visibility = default_visibility.resolve(item_visibility);
+            }
+            self.definitions.push(definition);
+        }
+
+        let default_visibility = self.default_visibility.as_ref().or(metadata.visibility.as_ref());
+        if let Some(default_visibility) = default_visibility {
+            for &index in &self.bare_definitions {
+                self.definitions[index].visibility = default_visibility.resolve(item_visibility);
+            }
+        }
+    }
+}
+
+/// Parses everything following a constructor name, ex: `new<U>(into, scale: f32) for <u8> where T: Clone`
+fn parse_definition_signature(input: ParseStream, definition: &mut CtorDefinition) -> syn::Result<()> {
+    if input.peek(Token![<]) {
+        definition.generics = input.parse()?;
+    }
+
+    parse_definition_properties(input, definition)?;
+
+    if input.parse::<Token![for]>().is_ok() {
+        definition.specialization = Some(input.parse()?);
+    }
+
+    if input.peek(Token![where]) {
+        let mut where_clause = WhereClause { where_token: input.parse()?, predicates: Punctuated::new() };
+        loop {
+            where_clause.predicates.push(input.parse()?);
+            // a comma can either separate predicates or constructor definitions
+            let fork = input.fork();
+            if fork.parse::<Comma>().is_err() || fork.parse::<WherePredicate>().is_err() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
+        definition.generics.where_clause = Some(where_clause);
+    }
+    Ok(())
+}
+
+/// Parses the optional parenthesized properties following a constructor name, ex: `new(into, scale: f32)`
+fn parse_definition_properties(input: ParseStream, definition: &mut CtorDefinition) -> syn::Result<()> {
+    if !input.peek(Paren) {
+        return Ok(());
+    }
+
+    let is_default = definition.attrs.contains(&CtorAttribute::Default);
+    let (properties, parameters, order, error_type) = consume_delimited(input, Delimiter::Parenthesis, |buffer| {
+        let mut properties = Vec::new();
+        let mut parameters = Vec::new();
+        let mut order = Vec::new();
+        let mut error_type = None;
+        while !buffer.is_empty() {
+            let ident = buffer.parse::<Ident>()?;
+            if buffer.parse::<Token![:]>().is_ok() {
+                parameters.push(CtorParameter { ident, parameter_type: buffer.parse()? });
+            } else if ident == GENERICS && buffer.parse::<Token![=]>().is_ok() {
+                // `impl` is a keyword, so it cannot be parsed as an ident
+                if buffer.parse::<Token![impl]>().is_err() {
+                    let value = buffer.parse::<Ident>()?;
+                    if value != NAMED {
+                        return Err(unknown_property_error(value.span(), &value.to_string(), GENERICS_VALUES));
+                    }
+                    properties.push(CtorAttribute::NamedGenerics);
+                }
+            } else if ident == ERROR && buffer.parse::<Token![=]>().is_ok() {
+                error_type = Some(buffer.parse()?);
+            } else if ident == ORDER && buffer.peek(Paren) {
+                order = consume_delimited(buffer, Delimiter::Parenthesis, |names| {
+                    Ok(Punctuated::<Ident, Comma>::parse_terminated(names)?.into_iter().collect())
+                })?;
+            } else {
+                properties.push(match ident.to_string().as_str() {
+                    ALL if is_default => CtorAttribute::DefaultAll,
+                    DEFAULT => CtorAttribute::DefaultAll,
+                    INTO => CtorAttribute::IntoAll,
+                    property_name => return Err(unknown_property_error(ident.span(), property_name, STRUCT_CTOR_PROPS))
+                });
+            }
+            if buffer.parse::<Comma>().is_err() {
+                break;
+            }
+        }
+        Ok((properties, parameters, order, error_type))
+    })?;
+
+    definition.attrs.extend(properties);
+    definition.parameters = parameters;
+    definition.parameter_order = order;
+    definition.error_type = error_type;
+    Ok(())
+}
+
+pub(crate) fn create_struct_token_stream(derive_input: DeriveInput, metadata: &Metadata) -> TokenStream {
+    if let Data::Struct(data) = derive_input.data {
+        let mut configuration = match try_parse_attributes_with_default(&derive_input.attrs, || {
+            CtorStructConfiguration::default()
+        }) {
+            Ok(config) => config,
+            Err(err) => return err.to_compile_error(),
+        };
+        configuration.apply_metadata(&derive_input.vis, metadata, || {
+            let mut definition = CtorDefinition::default();
+            if let Some(name) = &metadata.name {
+                definition.ident = name.clone();
+            }
+            if let Some(default_visibility) = &metadata.visibility {
+                definition.visibility = default_visibility.resolve(&derive_input.vis);
+            }
+            definition
+        });
+
+        let ident = derive_input.ident.clone();
+        let is_debug = configuration.debug;
+        let expansion = create_ctor_struct_impl(
+            derive_input.ident,
+            derive_input.generics,
+            data.fields,
+            configuration,
+            metadata,
+        );
+        return debug_expansion(&ident, is_debug, expansion);
+    }
+    Error::new(derive_input.ident.span(), "Expected a struct").into_compile_error()
+}
+
+fn create_ctor_struct_impl(
+    ident: Ident,
+    generics: Generics,
+    fields: Fields,
+    configuration: CtorStructConfiguration,
+    metadata: &Metadata,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut methods = Vec::new();
+    let mut specialized_impls = Vec::new();
+    let mut default_method = None;
+    let mut default_generics = None;
+    let mut diagnostics = Diagnostics::default();
+    let mut validator = Validator::default();
+    let impl_attributes = generated_impl_attributes();
+    let method_attributes = metadata.method_attributes();
+
+    let definitions = configuration.definitions;
+    for (i, definition) in definitions.iter().enumerate() {
+        validator.check_definition(definition, &definition.ident, &mut diagnostics);
+        let Some(mut meta) = diagnostics.check(generate_ctor_meta(&definitions, i, &fields, &generics, &configuration.markers, &metadata.runtime)) else {
+            continue;
+        };
+        validator.check_meta(definition, &meta, &mut diagnostics);
+        meta.infer_bounds(&generics);
+        diagnostics.check(meta.check_const(definition));
+
+        let is_default = definition.attrs.contains(&CtorAttribute::Default);
+
+        if let Some(arguments) = &definition.specialization {
+            if is_default {
+                diagnostics.push(Error::new(arguments.span(), SPECIALIZED_DEFAULT_ERR_MSG));
+                continue;
+            }
+            if diagnostics.check(meta.specialize(&generics, arguments)).is_none() {
+                continue;
+            }
+        }
+
+        let (method_generics, method_where_clause) = if is_default {
+            default_generics = diagnostics.check(meta.default_generics(&generics));
+            (quote! {}, quote! {})
+        } else {
+            meta.method_generics()
+        };
+        let Some(return_type) = diagnostics.check(meta.return_type()) else {
+            continue;
+        };
+        let is_fallible = meta.is_fallible();
+        let field_entries = meta.field_entries();
+        let field_idents = meta.field_idents;
+        let parameter_fields = meta.parameter_fields;
+        let parameter_bindings = meta.parameter_bindings;
+        let specialized_items = meta.specialized_items;
+        let generated_fields = meta.generated_fields;
+
+        // the method of a `Default` implementation cannot declare a visibility
+        let visibility = (!is_default).then_some(&definition.visibility);
+        let mut name = definition.ident.clone();
+        let const_tkn = if definition.attrs.contains(&CtorAttribute::Const) {
+            quote! { const }
+        } else {
+            quote! {}
+        };
+
+        if is_default {
+            name = Ident::new("default", definition.ident.span());
+        }
+
+        // the arguments of a tuple constructor cannot carry attributes, which is sound since the compiler removes
+        // disabled fields before the derive receives its input
+        let mut struct_generation = if let Fields::Unnamed(_) = &fields {
+            quote! { Self(#(#field_idents),*) }
+        } else {
+            quote! { Self { #(#field_entries),* } }
+        };
+
+        if is_fallible {
+            struct_generation = quote! { ::core::result::Result::Ok(#struct_generation) };
+        }
+
+        let method_token_stream = quote! {
+            #method_attributes
+            #visibility #const_tkn fn #name #method_generics(#(#parameter_fields),*) -> #return_type #method_where_clause {
+                #(#specialized_items)*
+                #(#parameter_bindings)*
+                #(#generated_fields)*
+                #struct_generation
+            }
+        };
+
+        if is_default {
+            default_method = Some(method_token_stream);
+        } else if let Some(arguments) = &definition.specialization {
+            specialized_impls.push(quote! {
+                #impl_attributes
+                impl #ident #arguments {
+                    #method_token_stream
+                }
+            });
+        } else {
+            methods.push(method_token_stream);
+        }
+    }
+
+    if let Err(err) = diagnostics.finish() {
+        return err.into_compile_error();
+    }
+
+    let default_impl = if let (Some(def_method), Some(def_generics)) = (default_method, default_generics) {
+        let (impl_generics, ty_generics, where_clause) = def_generics.split_for_impl();
+        quote! {
+            #impl_attributes
+            impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                #def_method
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    #[cfg(feature = "shorthand")]
+    let warnings = if metadata.shorthand_warnings {
+        crate::fields::foreign_attribute_warnings(&fields)
+    } else {
+        quote! {}
+    };
+    #[cfg(not(feature = "shorthand"))]
+    let warnings = quote! {};
+
+    quote! {
+        #impl_attributes
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#methods)*
+        }
+        #(#specialized_impls)*
+        #default_impl
+        #warnings
+    }
+}
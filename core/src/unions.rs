@@ -0,0 +1,210 @@
+// This file is almost a 1-to-1 copy of the enum file because unions work similarly to enums
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use proc_macro2::TokenStream;
+
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, FieldsNamed, Generics, Ident, LitBool, token, Visibility};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::token::{Comma, Pub};
+
+use crate::{cfg_attributes, CtorAttribute, CtorDefinition, DefaultVisibility, generated_impl_attributes, try_parse_attributes_with_default};
+use crate::constants::{ENUM_PROP_DEBUG as DEBUG, ENUM_PROP_PREFIX as PREFIX, ENUM_PROP_VIS as VIS, ENUM_PROP_VISIBILITY as VISIBILITY};
+use crate::debug::debug_expansion;
+use crate::diagnostics::{unknown_property_error, Diagnostics};
+use crate::metadata::Metadata;
+use crate::structs::CtorStructConfiguration;
+use crate::validation::Validator;
+
+const UNION_CTOR_PROPS: &[&str] = &[PREFIX, VISIBILITY, VIS, DEBUG];
+
+enum UnionConfigItem {
+    Visibility { visibility: DefaultVisibility },
+    Prefix { prefix: Ident },
+    Debug { debug: bool },
+}
+
+/// The configuration of a union. Properties which are not declared are filled by the metadata of the package.
+#[derive(Default)]
+pub struct CtorUnionConfiguration {
+    pub prefix: Option<Ident>,
+    pub default_visibility: Option<DefaultVisibility>,
+    /// whether the generated code is reported as a compile error, ex: `#[ctor(debug = true)]`
+    pub debug: bool,
+}
+
+impl CtorStructConfiguration {
+    fn from_union_field(configuration: &CtorUnionConfiguration, field_name: Ident, item_visibility: &Visibility) -> Self {
+        Self {
+            definitions: vec![union_field_definition(configuration, field_name, item_visibility)],
+            is_none: false,
+            ..Default::default()
+        }
+    }
+}
+
+/// The constructor of a union field which does not declare its own, named after the field
+fn union_field_definition(configuration: &CtorUnionConfiguration, field_name: Ident, item_visibility: &Visibility) -> CtorDefinition {
+    CtorDefinition {
+        visibility: match &configuration.default_visibility {
+            Some(default_visibility) => default_visibility.resolve(item_visibility),
+            None => Visibility::Public(Pub { span: Span::mixed_site() }),
+        },
+        ident: match &configuration.prefix {
+            None => field_name,
+            Some(prefix) => format_ident!("{}_{}", prefix, field_name, span = field_name.span()),
+        },
+        attrs: Default::default(),
+        parameters: Vec::new(),
+        parameter_order: Vec::new(),
+        generics: Default::default(),
+        specialization: None,
+        error_type: None,
+    }
+}
+
+impl CtorUnionConfiguration {
+    /// Fills the properties which are not declared by the union with the package-wide defaults of the metadata
+    pub fn apply_metadata(&mut self, metadata: &Metadata) {
+        self.prefix = self.prefix.take().or_else(|| metadata.prefix.clone());
+        self.default_visibility = self.default_visibility.take().or_else(|| metadata.visibility.clone());
+    }
+}
+
+impl Parse for CtorUnionConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut configuration = CtorUnionConfiguration::default();
+        loop {
+            match input.parse::<UnionConfigItem>()? {
+                UnionConfigItem::Visibility { visibility } => {
+                    configuration.default_visibility = Some(visibility)
+                }
+                UnionConfigItem::Prefix { prefix } => configuration.prefix = Some(prefix),
+                UnionConfigItem::Debug { debug } => configuration.debug = debug,
+            }
+            if input.parse::<Comma>().is_err() {
+                break;
+            }
+        }
+        Ok(configuration)
+    }
+}
+
+impl Parse for UnionConfigItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let property = input.parse::<Ident>()?;
+        let property_name = property.to_string();
+
+        input.parse::<token::Eq>()?;
+
+        Ok(match property_name.as_str() {
+            VIS | VISIBILITY => UnionConfigItem::Visibility { visibility: input.parse()?, },
+            PREFIX => UnionConfigItem::Prefix { prefix: input.parse()?, },
+            DEBUG => UnionConfigItem::Debug { debug: input.parse::<LitBool>()?.value },
+            _ => return Err(unknown_property_error(property.span(), &property_name, UNION_CTOR_PROPS)),
+        })
+    }
+}
+
+pub(crate) fn create_union_token_stream(derive_input: DeriveInput, metadata: &Metadata) -> TokenStream {
+    if let Data::Union(data) = derive_input.data {
+        let mut configuration = match try_parse_attributes_with_default(&derive_input.attrs, || {
+            CtorUnionConfiguration::default()
+        }) {
+            Ok(config) => config,
+            Err(err) => return err.to_compile_error(),
+        };
+        configuration.apply_metadata(metadata);
+
+        let ident = derive_input.ident.clone();
+        let is_debug = configuration.debug;
+        let expansion = create_ctor_union_impl(
+            derive_input.ident,
+            derive_input.vis,
+            derive_input.generics,
+            data.fields,
+            configuration,
+            metadata,
+        );
+        return debug_expansion(&ident, is_debug, expansion);
+    }
+    Error::new(derive_input.ident.span(), "Expected a union").into_compile_error()
+}
+
+fn create_ctor_union_impl(
+    ident: Ident,
+    item_visibility: Visibility,
+    generics: Generics,
+    fields: FieldsNamed,
+    configuration: CtorUnionConfiguration,
+    metadata: &Metadata,
+) -> TokenStream {
+    let mut methods = Vec::new();
+    let mut diagnostics = Diagnostics::default();
+    let mut validator = Validator::default();
+    let impl_attributes = generated_impl_attributes();
+    let method_attributes = metadata.method_attributes();
+    
+    for field in fields.named {
+        let Some(field_name) = field.ident else {
+            diagnostics.push(Error::new(field.ty.span(), "Expected a named union field"));
+            continue;
+        };
+        let field_type = field.ty;
+        let cfg_attrs = cfg_attributes(&field.attrs);
+
+        let Some(mut field_config) = diagnostics.check(try_parse_attributes_with_default(&field.attrs, || {
+            CtorStructConfiguration::from_union_field(&configuration, field_name.clone(), &item_visibility)
+        })) else {
+            continue;
+        };
+
+        // stop generation of method if none
+        if field_config.is_none {
+            continue;
+        }
+        field_config.apply_metadata(&item_visibility, metadata, || {
+            union_field_definition(&configuration, field_name.clone(), &item_visibility)
+        });
+
+        for def in field_config.definitions {
+            validator.check_definition(&def, &def.ident, &mut diagnostics);
+            let visibility = def.visibility;
+            let name = def.ident;
+            let ty = field_type.clone();
+
+            let const_tkn = if def.attrs.contains(&CtorAttribute::Const) {
+                quote! { const }
+            } else {
+                quote! {}
+            };
+            
+            let method_token_stream = quote! {
+                #method_attributes
+                #(#cfg_attrs)*
+                #visibility #const_tkn fn #name(#field_name: #ty) -> Self {
+                    Self { #field_name }
+                }
+            };
+            
+            methods.push(method_token_stream);
+        }
+    }
+
+    if let Err(err) = diagnostics.finish() {
+        return err.into_compile_error();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    
+    quote! {
+        #impl_attributes
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::Error;
+
+use crate::constants::{DEFAULT_CTOR_ERR_MSG, DUPLICATE_CTOR_ERR_MSG, MULTIPLE_DEFAULT_ERR_MSG, UNUSED_SELF_EXPR_ERR_MSG};
+use crate::diagnostics::Diagnostics;
+use crate::expressions::{collect_references, FieldReference};
+use crate::fields::{ConstructorMeta, FieldConfigProperty};
+use crate::{CtorAttribute, CtorDefinition};
+
+/// Validates the configuration of an entire type, catching mistakes which span several constructors (ex: two
+/// variants of an enum generating the same method) in addition to those within a single constructor.
+#[derive(Default)]
+pub(crate) struct Validator {
+    /// the registered method names, with the generic arguments of specialized constructors, ex: `bytes for <u8>`
+    names: Vec<(Ident, Option<String>)>,
+    default: Option<Ident>,
+}
+
+impl Validator {
+    /// Registers the constructor under the method name it is generated with, reporting it if the name is taken.
+    /// Specialized constructors are generated within separate `impl` blocks, so they only conflict with a constructor
+    /// of the same name which is either unspecialized or specialized to the same arguments.
+    pub(crate) fn check_definition(&mut self, definition: &CtorDefinition, name: &Ident, diagnostics: &mut Diagnostics) {
+        if definition.attrs.contains(&CtorAttribute::Default) {
+            if self.default.is_some() {
+                diagnostics.push(Error::new(definition.ident.span(), MULTIPLE_DEFAULT_ERR_MSG));
+            }
+            self.default = Some(definition.ident.clone());
+            return;
+        }
+        let specialization = definition.specialization.as_ref().map(|arguments| arguments.to_token_stream().to_string());
+        let is_taken = self.names.iter().any(|(other, other_specialization)| other == name
+            && (specialization.is_none() || other_specialization.is_none() || *other_specialization == specialization));
+        if is_taken {
+            diagnostics.push(Error::new(definition.ident.span(),
+                DUPLICATE_CTOR_ERR_MSG.replace("{name}", &name.to_string())));
+        }
+        self.names.push((name.clone(), specialization));
+    }
+
+    /// Reports the fields of the constructor which are configured in a way that cannot produce a valid method
+    pub(crate) fn check_meta(&self, definition: &CtorDefinition, meta: &ConstructorMeta, diagnostics: &mut Diagnostics) {
+        if definition.attrs.contains(&CtorAttribute::Default) {
+            for parameter in &meta.parameter_fields {
+                diagnostics.push(Error::new(parameter.span, DEFAULT_CTOR_ERR_MSG));
+            }
+        }
+
+        for field in &meta.generated_fields {
+            if let FieldConfigProperty::Expression { expression, self_referencing: true, .. } = &field.configuration {
+                let mut references = Vec::new();
+                if diagnostics.check(collect_references(expression, &mut references)).is_none() {
+                    continue;
+                }
+                let is_referenced = references.iter().any(|reference| match reference {
+                    FieldReference::Value(ident) | FieldReference::Raw(ident) => *ident == field.field_ident,
+                });
+                if !is_referenced {
+                    diagnostics.push(Error::new(field.span,
+                        UNUSED_SELF_EXPR_ERR_MSG.replace("{field}", &field.field_ident.to_string())));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use derive_ctor_core::constants::{CONST_DEFAULT_ERR_MSG, CONST_FIELD_ERR_MSG, DEBUG_EXPANSION_MSG, DEFAULT_CTOR_ERR_MSG,
+    DUPLICATE_CTOR_ERR_MSG, ITER_SCALAR_ERR_MSG, MULTIPLE_DEFAULT_ERR_MSG, SPECIALIZED_ASSOC_ERR_MSG, SUGGESTION_MSG,
+    UNUSED_SELF_EXPR_ERR_MSG};
+use derive_ctor_core::debug::pretty_print;
+use derive_ctor_core::enums::CtorEnumConfiguration;
+use derive_ctor_core::expand;
+use derive_ctor_core::metadata::Metadata;
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{parse_quote, LitStr};
+
+const IMPL_ATTRIBUTES: &str = "#[automatically_derived]\n#[allow(clippy::too_many_arguments, clippy::new_without_default)]";
+
+/// Collects the messages of the compile errors reported by an expansion
+fn error_messages(expansion: TokenStream) -> Vec<String> {
+    expansion.into_iter().filter_map(|token| match token {
+        TokenTree::Group(group) => syn::parse2::<LitStr>(group.stream()).ok().map(|message| message.value()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn test_struct_expansion() {
+    let input = parse_quote! {
+        #[ctor(pub new, pub(crate) with_name(default))]
+        struct MyStruct {
+            #[ctor(into)]
+            name: String,
+            value: u32
+        }
+    };
+    let expected = [
+        IMPL_ATTRIBUTES,
+        "impl MyStruct {",
+        "    pub fn new(name: impl ::core::convert::Into<String>, value: u32) -> Self {",
+        "        let name: String = ::core::convert::Into::into(name);",
+        "        Self {",
+        "            name, value",
+        "        }",
+        "    }",
+        "    pub(crate) fn with_name() -> Self {",
+        "        let name: String = ::core::default::Default::default();",
+        "        let value: u32 = ::core::default::Default::default();",
+        "        Self {",
+        "            name, value",
+        "        }",
+        "    }",
+        "}",
+    ].join("\n");
+    assert_eq!(expected, pretty_print(expand(input, &Metadata::default())));
+}
+
+#[test]
+fn test_enum_expansion() {
+    let input = parse_quote! {
+        #[ctor(prefix = new)]
+        enum MyEnum {
+            #[ctor(default)]
+            Unit,
+            Tuple(u32),
+            Named { value: bool }
+        }
+    };
+    let expected = [
+        IMPL_ATTRIBUTES,
+        "impl MyEnum {",
+        "    pub fn new_tuple(arg0: u32) -> Self {",
+        "        Self::Tuple(arg0)",
+        "    }",
+        "    pub fn new_named(value: bool) -> Self {",
+        "        Self::Named {",
+        "            value",
+        "        }",
+        "    }",
+        "}",
+        IMPL_ATTRIBUTES,
+        "impl ::core::default::Default for MyEnum {",
+        "    fn default() -> Self {",
+        "        Self::Unit",
+        "    }",
+        "}",
+    ].join("\n");
+    assert_eq!(expected, pretty_print(expand(input, &Metadata::default())));
+}
+
+#[test]
+fn test_metadata_expansion() {
+    let metadata = Metadata {
+        name: Some(parse_quote!(create)),
+        attributes: vec![parse_quote!(must_use)],
+        ..Metadata::default()
+    };
+    let input = parse_quote! {
+        struct MyStruct(u32);
+    };
+    let expected = [
+        IMPL_ATTRIBUTES,
+        "impl MyStruct {",
+        "    #[must_use]",
+        "    pub fn create(arg0: u32) -> Self {",
+        "        Self(arg0)",
+        "    }",
+        "}",
+    ].join("\n");
+    assert_eq!(expected, pretty_print(expand(input, &metadata)));
+}
+
+#[test]
+fn test_enum_configuration_with_metadata() {
+    let metadata = Metadata { prefix: Some(parse_quote!(create)), ..Metadata::default() };
+    let mut configuration: CtorEnumConfiguration = parse_quote!(vis = pub(crate), debug = true);
+    configuration.apply_metadata(&metadata);
+    assert_eq!(Some("create".to_string()), configuration.prefix.map(|prefix| prefix.to_string()));
+    assert!(configuration.default_visibility.is_some());
+    assert!(configuration.debug);
+}
+
+#[test]
+fn test_expansion_error() {
+    let input = parse_quote! {
+        #[ctor(default)]
+        struct MyStruct {
+            value: u32
+        }
+    };
+    let expansion = pretty_print(expand(input, &Metadata::default()));
+    assert!(expansion.starts_with("::core::compile_error!"));
+}
+
+#[test]
+fn test_metadata_visibility_with_default() {
+    let metadata = Metadata {
+        visibility: Some(parse_quote!(pub(crate))),
+        ..Metadata::default()
+    };
+    let input = parse_quote! {
+        #[ctor(new, default)]
+        struct MyStruct {
+            #[ctor(default)]
+            value: u32
+        }
+    };
+    let expected = [
+        IMPL_ATTRIBUTES,
+        "impl MyStruct {",
+        "    pub(crate) fn new() -> Self {",
+        "        let value: u32 = ::core::default::Default::default();",
+        "        Self {",
+        "            value",
+        "        }",
+        "    }",
+        "}",
+        IMPL_ATTRIBUTES,
+        "impl ::core::default::Default for MyStruct {",
+        "    fn default() -> Self {",
+        "        let value: u32 = ::core::default::Default::default();",
+        "        Self {",
+        "            value",
+        "        }",
+        "    }",
+        "}",
+    ].join("\n");
+    assert_eq!(expected, pretty_print(expand(input, &metadata)));
+}
+
+#[test]
+fn test_const_default_runtime_path() {
+    let metadata = Metadata {
+        runtime: parse_quote!(::my_crate::runtime),
+        ..Metadata::default()
+    };
+    let input = parse_quote! {
+        #[ctor(pub const new)]
+        struct MyStruct {
+            #[ctor(default)]
+            value: u32
+        }
+    };
+    let expected = [
+        IMPL_ATTRIBUTES,
+        "impl MyStruct {",
+        "    pub const fn new() -> Self {",
+        "        let value: u32 = <u32 as ::my_crate::runtime::ConstDefault>::DEFAULT;",
+        "        Self {",
+        "            value",
+        "        }",
+        "    }",
+        "}",
+    ].join("\n");
+    assert_eq!(expected, pretty_print(expand(input, &metadata)));
+}
+
+#[test]
+fn test_duplicate_constructor_error() {
+    let input = parse_quote! {
+        #[ctor(new, pub new)]
+        struct MyStruct(u32);
+    };
+    assert_eq!(vec![DUPLICATE_CTOR_ERR_MSG.replace("{name}", "new")], error_messages(expand(input, &Metadata::default())));
+}
+
+#[test]
+fn test_duplicate_specialized_constructor_error() {
+    let input = parse_quote! {
+        #[ctor(bytes for <u8>, bytes for <u16>, bytes for <u8>, words, words for <u16>)]
+        struct MyStruct<T>(T);
+    };
+    assert_eq!(
+        vec![DUPLICATE_CTOR_ERR_MSG.replace("{name}", "bytes"), DUPLICATE_CTOR_ERR_MSG.replace("{name}", "words")],
+        error_messages(expand(input, &Metadata::default()))
+    );
+}
+
+#[test]
+fn test_multiple_default_error() {
+    let input = parse_quote! {
+        enum MyEnum {
+            #[ctor(default)]
+            First,
+            #[ctor(default)]
+            Second,
+        }
+    };
+    assert_eq!(vec![MULTIPLE_DEFAULT_ERR_MSG], error_messages(expand(input, &Metadata::default())));
+}
+
+#[test]
+fn test_default_with_parameters_error() {
+    let input = parse_quote! {
+        #[ctor(default)]
+        struct MyStruct {
+            value: u32
+        }
+    };
+    assert_eq!(vec![DEFAULT_CTOR_ERR_MSG], error_messages(expand(input, &Metadata::default())));
+}
+
+#[test]
+fn test_const_field_error() {
+    let input = parse_quote! {
+        #[ctor(const new)]
+        struct MyStruct {
+            #[ctor(into)]
+            first: String,
+            #[ctor(iter(u8))]
+            second: Vec<u8>,
+            #[ctor(expr(10))]
+            third: u32
+        }
+    };
+    assert_eq!(
+        vec![
+            CONST_FIELD_ERR_MSG.replace("{field}", "first")
+                .replace("{reason}", "`into` calls `Into::into`, which is not a const fn"),
+            CONST_FIELD_ERR_MSG.replace("{field}", "second")
+                .replace("{reason}", "`iter` consumes an iterator, which cannot be done in a const fn"),
+        ],
+        error_messages(expand(input, &Metadata::default()))
+    );
+}
+
+#[test]
+fn test_const_default_error() {
+    let input = parse_quote! {
+        #[ctor(const default)]
+        struct MyStruct;
+    };
+    assert_eq!(vec![CONST_DEFAULT_ERR_MSG], error_messages(expand(input, &Metadata::default())));
+}
+
+#[test]
+fn test_unused_self_expr_error() {
+    let input = parse_quote! {
+        struct MyStruct {
+            #[ctor(expr!(10))]
+            value: u32
+        }
+    };
+    assert_eq!(
+        vec![UNUSED_SELF_EXPR_ERR_MSG.replace("{field}", "value")],
+        error_messages(expand(input, &Metadata::default()))
+    );
+}
+
+#[test]
+fn test_self_expr_referenced_by_format_string() {
+    let input = parse_quote! {
+        struct MyStruct {
+            #[ctor(expr!(format!("{value}")))]
+            value: String
+        }
+    };
+    assert!(error_messages(expand(input, &Metadata::default())).is_empty());
+}
+
+#[test]
+fn test_multiple_errors_reported_at_once() {
+    let input = parse_quote! {
+        #[ctor(new, new)]
+        struct MyStruct {
+            #[ctor(iter)]
+            value: u32,
+            #[ctor(defualt)]
+            other: u32
+        }
+    };
+    let messages = error_messages(expand(input, &Metadata::default()));
+    assert_eq!(3, messages.len());
+    assert_eq!(ITER_SCALAR_ERR_MSG.replace("{type}", "u32"), messages[0]);
+    assert!(messages[1].starts_with("Unexpected property: \"defualt\""));
+    assert_eq!(DUPLICATE_CTOR_ERR_MSG.replace("{name}", "new"), messages[2]);
+}
+
+#[test]
+fn test_unknown_property_suggestion() {
+    let input = parse_quote! {
+        struct MyStruct {
+            #[ctor(defualt)]
+            value: u32
+        }
+    };
+    let messages = error_messages(expand(input, &Metadata::default()));
+    assert_eq!(1, messages.len());
+    assert!(messages[0].ends_with(&SUGGESTION_MSG.replace("{suggestion}", "default")));
+}
+
+#[test]
+fn test_specialized_associated_type_error() {
+    let input = parse_quote! {
+        #[ctor(new, empty for <Vec<u8>>)]
+        struct MyStruct<T: IntoIterator + Clone> {
+            #[ctor(default = empty)]
+            first: Option<T::Item>
+        }
+    };
+    assert_eq!(
+        vec![SPECIALIZED_ASSOC_ERR_MSG.replace("{type}", "T :: Item").replace("{param}", "T")],
+        error_messages(expand(input, &Metadata::default()))
+    );
+}
+
+#[test]
+fn test_debug_expansion() {
+    let input = parse_quote! {
+        #[ctor(debug = true)]
+        enum MyEnum {
+            Unit
+        }
+    };
+    let messages = error_messages(expand(input, &Metadata::default()));
+    assert_eq!(1, messages.len());
+    let (header, _) = DEBUG_EXPANSION_MSG.split_once("{expansion}").unwrap();
+    assert!(messages[0].starts_with(&header.replace("{type}", "MyEnum")));
+
+    let input = parse_quote! {
+        #[ctor(new, debug = false)]
+        struct MyStruct;
+    };
+    assert!(error_messages(expand(input, &Metadata::default())).is_empty());
+}
@@ -0,0 +1,113 @@
+//! Runtime support for [derive-ctor](https://crates.io/crates/derive-ctor).
+//!
+//! `#[ctor(default)]` fields of a `const` constructor are generated with
+//! `<T as ConstDefault>::DEFAULT`, since `Default::default()` cannot be called within a `const fn`.
+//! Within a fallible constructor, an array field filled by an iterator returns an [`ArrayLengthError`] rather than
+//! panicking when the iterator yields the wrong number of items.
+
+#![no_std]
+
+use core::marker::{PhantomData, PhantomPinned};
+
+/// A type with a default value which can be evaluated at compile-time.
+///
+/// # Example
+///
+/// ```
+/// use derive_ctor_runtime::ConstDefault;
+///
+/// struct Counter {
+///     count: u32
+/// }
+///
+/// impl ConstDefault for Counter {
+///     const DEFAULT: Self = Counter { count: 0 };
+/// }
+///
+/// const COUNTERS: [Counter; 2] = <[Counter; 2]>::DEFAULT;
+/// ```
+pub trait ConstDefault: Sized {
+    /// The default value of the type
+    const DEFAULT: Self;
+}
+
+macro_rules! impl_const_default {
+    ($value:expr => $($ty:ty),*) => {
+        $(
+            impl ConstDefault for $ty {
+                const DEFAULT: Self = $value;
+            }
+        )*
+    };
+}
+
+impl_const_default!(0 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_default!(0.0 => f32, f64);
+impl_const_default!(false => bool);
+impl_const_default!('\x00' => char);
+impl_const_default!(() => ());
+impl_const_default!("" => &str);
+impl_const_default!(PhantomPinned => PhantomPinned);
+
+impl<T: ?Sized> ConstDefault for PhantomData<T> {
+    const DEFAULT: Self = PhantomData;
+}
+
+impl<T> ConstDefault for Option<T> {
+    const DEFAULT: Self = None;
+}
+
+impl<T> ConstDefault for &[T] {
+    const DEFAULT: Self = &[];
+}
+
+impl<T: ConstDefault, const N: usize> ConstDefault for [T; N] {
+    const DEFAULT: Self = [T::DEFAULT; N];
+}
+
+macro_rules! impl_const_default_tuple {
+    ($($ty:ident),*) => {
+        impl<$($ty: ConstDefault),*> ConstDefault for ($($ty,)*) {
+            const DEFAULT: Self = ($($ty::DEFAULT,)*);
+        }
+    };
+}
+
+impl_const_default_tuple!(A);
+impl_const_default_tuple!(A, B);
+impl_const_default_tuple!(A, B, C);
+impl_const_default_tuple!(A, B, C, D);
+impl_const_default_tuple!(A, B, C, D, E);
+impl_const_default_tuple!(A, B, C, D, E, F);
+impl_const_default_tuple!(A, B, C, D, E, F, G);
+impl_const_default_tuple!(A, B, C, D, E, F, G, H);
+impl_const_default_tuple!(A, B, C, D, E, F, G, H, I);
+impl_const_default_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_const_default_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_const_default_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// The error returned by a fallible constructor when the iterator filling an array field (ex: `#[ctor(iter)]`)
+/// yields a different number of items than the length of the array. The error type of the constructor must
+/// implement `From<ArrayLengthError>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrayLengthError {
+    /// the iterator yielded `found` items, fewer than the `expected` length of the array
+    TooFew { expected: usize, found: usize },
+    /// the iterator yielded more items than the `expected` length of the array
+    TooMany { expected: usize },
+}
+
+impl core::fmt::Display for ArrayLengthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ArrayLengthError::TooFew { expected, found } => {
+                write!(f, "iterator yielded {} items, fewer than the length of the array ({})", found, expected)
+            }
+            ArrayLengthError::TooMany { expected } => {
+                write!(f, "iterator yielded more items than the length of the array ({})", expected)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ArrayLengthError {}
@@ -0,0 +1,18 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+enum ConfiguredVariants {
+    #[cfg(test)]
+    #[ctor(enabled, default)]
+    Enabled,
+    #[cfg(not(test))]
+    Disabled,
+    Tuple(#[cfg(test)] u32, #[cfg(not(test))] u32),
+}
+
+#[test]
+fn test_cfg_variants() {
+    assert_eq!(ConfiguredVariants::Enabled, ConfiguredVariants::enabled());
+    assert_eq!(ConfiguredVariants::Enabled, ConfiguredVariants::default());
+    assert_eq!(ConfiguredVariants::Tuple(5), ConfiguredVariants::tuple(5));
+}
@@ -41,3 +41,15 @@ fn test_visibility_and_prefix_enum() {
     let result = PrefixAndVisibilityEnum::new_element();
     assert!(matches!(result, PrefixAndVisibilityEnum::Element))
 }
+
+#[derive(ctor)]
+#[ctor(prefix = new, naming = preserve)]
+enum PreservedNamingEnum {
+    Element,
+}
+
+#[test]
+fn test_preserved_naming_enum() {
+    let result = PreservedNamingEnum::new_Element();
+    assert!(matches!(result, PreservedNamingEnum::Element))
+}
@@ -0,0 +1,67 @@
+#![no_implicit_prelude]
+
+use ::derive_ctor::ctor;
+use ::std::string::String;
+use ::std::vec::Vec;
+
+// generated code must not resolve to these in place of the core traits
+#[allow(dead_code)]
+trait Default {}
+#[allow(dead_code)]
+trait Into<T> {}
+#[allow(dead_code)]
+trait Clone {}
+
+fn string(value: &str) -> String {
+    ::std::convert::From::from(value)
+}
+
+#[derive(ctor, ::std::fmt::Debug, ::std::cmp::PartialEq)]
+#[ctor(pub new, pub with_defaults(default))]
+struct Everything {
+    #[ctor(into)]
+    name: String,
+    #[ctor(cloned = new)]
+    label: String,
+    #[ctor(iter(into u16))]
+    values: Vec<u16>,
+    #[ctor(iter = new)]
+    bytes: [u8; 2],
+    #[ctor(repeat(1))]
+    repeated: [u8; 3],
+    #[ctor(default)]
+    count: u32,
+}
+
+#[derive(ctor, ::std::fmt::Debug, ::std::cmp::PartialEq)]
+#[ctor(default)]
+struct Defaulted {
+    #[ctor(default)]
+    name: String,
+}
+
+#[derive(ctor, ::std::fmt::Debug, ::std::cmp::PartialEq)]
+struct Tuple(u32, #[ctor(expr(arg0 * 2))] u32);
+
+#[derive(ctor, ::std::fmt::Debug, ::std::cmp::PartialEq)]
+enum Choice {
+    Empty,
+    Value(#[ctor(into)] String),
+}
+
+#[test]
+fn test_generated_code_without_prelude() {
+    let label = string("label");
+    let everything = Everything::new("name", &label, [1u8], [2, 3]);
+    ::std::assert_eq!(string("name"), everything.name);
+    ::std::assert_eq!(label, everything.label);
+    ::std::assert_eq!(::std::vec![1u16], everything.values);
+    ::std::assert_eq!([2, 3], everything.bytes);
+    ::std::assert_eq!([1, 1, 1], everything.repeated);
+    ::std::assert_eq!(0, everything.count);
+    ::std::assert_eq!(String::new(), Everything::with_defaults().label);
+    ::std::assert_eq!(Defaulted { name: String::new() }, <Defaulted as ::core::default::Default>::default());
+    ::std::assert_eq!(Tuple(2, 4), Tuple::new(2));
+    ::std::assert_eq!(Choice::Empty, Choice::empty());
+    ::std::assert_eq!(Choice::Value(string("value")), Choice::value("value"));
+}
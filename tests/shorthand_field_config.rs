@@ -1,3 +1,6 @@
+#![allow(unknown_lints)]
+// the "shorthand-warnings" feature reports foreign attributes as deprecation warnings
+#![allow(deprecated)]
 #[cfg(feature = "shorthand")]
 use derive_ctor::ctor;
 
@@ -29,4 +32,58 @@ fn test_struct_with_shorthand() {
         value4: vec![1],
         value5: None
     }, test);
-}
\ No newline at end of file
+}
+#[cfg(feature = "shorthand")]
+#[derive(ctor, Debug, PartialEq)]
+struct ShorthandForeignAttributes {
+    // attributes of other crates (or lints) are never treated as ctor properties
+    #[allow(default)]
+    value1: u32,
+    #[default]
+    value2: u32,
+}
+
+#[test]
+#[cfg(feature = "shorthand")]
+fn test_struct_with_foreign_attributes() {
+    let test = ShorthandForeignAttributes::new(5);
+    assert_eq!(ShorthandForeignAttributes { value1: 5, value2: 0 }, test);
+}
+
+#[cfg(feature = "shorthand")]
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new, with_defaults, empty)]
+struct ShorthandApplications {
+    #[default = "with_defaults, empty"]
+    value1: u32,
+    #[into = 1]
+    value2: String,
+}
+
+#[test]
+#[cfg(feature = "shorthand")]
+fn test_struct_with_shorthand_applications() {
+    assert_eq!(ShorthandApplications { value1: 1, value2: "Foo".to_string() },
+        ShorthandApplications::new(1, "Foo".to_string()));
+    assert_eq!(ShorthandApplications { value1: 0, value2: "Bar".to_string() },
+        ShorthandApplications::with_defaults("Bar"));
+    assert_eq!(ShorthandApplications { value1: 0, value2: "Baz".to_string() },
+        ShorthandApplications::empty("Baz".to_string()));
+}
+
+#[cfg(feature = "shorthand")]
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(default = std)]
+enum ShorthandStdDefault {
+    First(u32),
+    #[default]
+    Second,
+}
+
+#[test]
+#[cfg(feature = "shorthand")]
+fn test_enum_with_std_default_variant() {
+    assert_eq!(ShorthandStdDefault::Second, ShorthandStdDefault::default());
+    assert_eq!(ShorthandStdDefault::Second, ShorthandStdDefault::second());
+    assert_eq!(ShorthandStdDefault::First(1), ShorthandStdDefault::first(1));
+}
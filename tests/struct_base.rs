@@ -60,6 +60,7 @@ where
     item: T,
 }
 
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
 #[derive(ctor, Debug, PartialEq)]
 pub struct StructWithClosure {
     closure: fn(usize) -> bool,
@@ -74,6 +75,7 @@ where
 }
 
 #[test]
+#[allow(clippy::unnecessary_cast)]
 fn test_generic_structs() {
     let generic1: GenericStruct<usize> = GenericStruct::new(400);
     assert_eq!(GenericStruct { item: 400 as usize }, generic1);
@@ -0,0 +1,35 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+struct ConfiguredFields {
+    value: u32,
+    #[cfg(not(test))]
+    disabled: u32,
+    #[cfg(test)]
+    #[ctor(into)]
+    enabled: u32,
+    #[cfg(test)]
+    #[ctor(expr(value * 2))]
+    doubled: u32,
+}
+
+#[test]
+fn test_cfg_fields() {
+    let test = ConfiguredFields::new(2, 3u8);
+    assert_eq!(ConfiguredFields { value: 2, enabled: 3, doubled: 4 }, test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct ConfiguredAttributes {
+    value: u32,
+    #[cfg_attr(test, ctor(default))]
+    defaulted: u32,
+    #[cfg_attr(not(test), ctor(default))]
+    required: u32,
+}
+
+#[test]
+fn test_cfg_attr_fields() {
+    let test = ConfiguredAttributes::new(1, 2);
+    assert_eq!(ConfiguredAttributes { value: 1, defaulted: 0, required: 2 }, test);
+}
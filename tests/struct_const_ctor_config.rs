@@ -50,3 +50,21 @@ fn test_struct_with_multiple_methods() {
     let v3: ConstStructMultiple = ConstStructMultiple::m4(4);
     assert_eq!(ConstStructMultiple { value: 4 }, v3);
 }
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(const new, converting)]
+struct ConstStructMixedFields {
+    #[ctor(expr(10))]
+    generated: u8,
+    #[ctor(into = [converting])]
+    converted: u16,
+}
+
+#[test]
+fn test_const_struct_with_compatible_fields() {
+    const V0: ConstStructMixedFields = ConstStructMixedFields::new(2);
+    assert_eq!(ConstStructMixedFields { generated: 10, converted: 2 }, V0);
+
+    let v1 = ConstStructMixedFields::converting(3u8);
+    assert_eq!(ConstStructMixedFields { generated: 10, converted: 3 }, v1);
+}
@@ -0,0 +1,52 @@
+use std::marker::PhantomData;
+
+use derive_ctor::ctor;
+use derive_ctor_runtime::ConstDefault;
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(const new)]
+struct ConstDefaultStruct {
+    value: u8,
+    #[ctor(default)]
+    count: usize,
+    #[ctor(default)]
+    name: Option<&'static str>,
+    #[ctor(default)]
+    pair: (bool, [i16; 3]),
+    marker: PhantomData<u8>,
+}
+
+#[test]
+fn test_const_struct_with_default_fields() {
+    const VALUE: ConstDefaultStruct = ConstDefaultStruct::new(1);
+    assert_eq!(
+        ConstDefaultStruct {
+            value: 1,
+            count: 0,
+            name: None,
+            pair: (false, [0; 3]),
+            marker: PhantomData
+        },
+        VALUE
+    );
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(const new(default))]
+struct ConstDefaultAllGeneric<T> {
+    value: T,
+    other: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Custom(u8);
+
+impl ConstDefault for Custom {
+    const DEFAULT: Self = Custom(7);
+}
+
+#[test]
+fn test_const_generic_struct_with_default_all() {
+    const VALUE: ConstDefaultAllGeneric<Custom> = ConstDefaultAllGeneric::new();
+    assert_eq!(ConstDefaultAllGeneric { value: Custom(7), other: 0 }, VALUE);
+}
@@ -56,3 +56,15 @@ fn test_field_struct_with_custom_ctor_name() {
     let field_struct = FieldStructCustomCtor::init(15);
     assert_eq!(FieldStructCustomCtor { value: 15 }, field_struct);
 }
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new, debug)]
+struct DebugNamedStruct {
+    value: u32,
+}
+
+#[test]
+fn test_method_named_debug() {
+    assert_eq!(DebugNamedStruct { value: 1 }, DebugNamedStruct::new(1));
+    assert_eq!(DebugNamedStruct { value: 2 }, DebugNamedStruct::debug(2));
+}
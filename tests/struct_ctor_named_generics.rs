@@ -0,0 +1,87 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(generics = named), other(generics = impl))]
+struct NamedGenericsStruct {
+    #[ctor(into)]
+    name: String,
+    #[ctor(iter(u8))]
+    items: Vec<u8>,
+    value: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(into, generics = named))]
+struct NamedGenericsIntoAll<T> {
+    item: T,
+    label: String,
+}
+
+#[test]
+fn test_named_generics() {
+    let test = NamedGenericsStruct::new::<&str, [u8; 2]>("Foo", [1, 2], 3);
+    assert_eq!(NamedGenericsStruct { name: String::from("Foo"), items: vec![1, 2], value: 3 }, test);
+
+    let test = NamedGenericsStruct::other("Bar", vec![3], 4);
+    assert_eq!(NamedGenericsStruct { name: String::from("Bar"), items: vec![3], value: 4 }, test);
+}
+
+#[test]
+fn test_named_generics_with_struct_generics() {
+    let test: NamedGenericsIntoAll<u64> = NamedGenericsIntoAll::new(5u32, "Baz");
+    assert_eq!(NamedGenericsIntoAll { item: 5u64, label: String::from("Baz") }, test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(into, generics = named))]
+struct SimilarFieldNames {
+    item: String,
+    item_: String,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+enum StructGenericCollision<TValue> {
+    Generic(TValue),
+    #[ctor(new(generics = named))]
+    Named {
+        #[ctor(into)]
+        value: String,
+    },
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new<TName: Into<u32>>(generics = named, extra: TName))]
+struct DefinitionGenericCollision {
+    #[ctor(into)]
+    name: String,
+    #[ctor(expr(extra.into()))]
+    extra_value: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(generics = named))]
+struct TryIntoItemCollision {
+    #[ctor(iter(try_into u8))]
+    values: Vec<u8>,
+    #[ctor(into)]
+    values_item: String,
+}
+
+#[test]
+fn test_named_generics_of_similar_field_names() {
+    let test = SimilarFieldNames::new::<&str, char>("Foo", 'b');
+    assert_eq!(SimilarFieldNames { item: String::from("Foo"), item_: String::from("b") }, test);
+}
+
+#[test]
+fn test_named_generics_do_not_shadow_generics() {
+    let test: StructGenericCollision<u8> = StructGenericCollision::new("Foo");
+    assert_eq!(StructGenericCollision::Named { value: String::from("Foo") }, test);
+    assert_eq!(StructGenericCollision::Generic(1u8), StructGenericCollision::generic(1u8));
+
+    let test = DefinitionGenericCollision::new("Bar", 1u8);
+    assert_eq!(DefinitionGenericCollision { name: String::from("Bar"), extra_value: 1 }, test);
+
+    let test = TryIntoItemCollision::new([1u32, 2], "Baz");
+    assert_eq!(Ok(TryIntoItemCollision { values: vec![1, 2], values_item: String::from("Baz") }), test);
+}
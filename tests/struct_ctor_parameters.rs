@@ -0,0 +1,58 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new, scaled(scale: u32))]
+struct ScaledStruct {
+    #[ctor(expr(base * scale) = scaled)]
+    width: u32,
+    #[ctor(expr(10 * scale) = scaled)]
+    height: u32,
+    base: u32,
+}
+
+#[test]
+fn test_ctor_with_virtual_parameter() {
+    let test = ScaledStruct::new(1, 2, 3);
+    assert_eq!(ScaledStruct { width: 1, height: 2, base: 3 }, test);
+
+    let scaled = ScaledStruct::scaled(4, 3);
+    assert_eq!(ScaledStruct { width: 12, height: 30, base: 4 }, scaled);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(into, seed: u64))]
+struct MixedPropertiesStruct {
+    name: String,
+    #[ctor(expr(seed.wrapping_mul(31)))]
+    hash: u64,
+}
+
+#[test]
+fn test_ctor_with_properties_and_virtual_parameter() {
+    let test = MixedPropertiesStruct::new("Foo", 2);
+    assert_eq!(MixedPropertiesStruct { name: String::from("Foo"), hash: 62 }, test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(labeled(scale: u32))]
+struct FormattedParameterStruct {
+    #[ctor(expr(format!("x{scale}")))]
+    label: String,
+}
+
+#[test]
+fn test_ctor_with_virtual_parameter_in_format_string() {
+    let test = FormattedParameterStruct::labeled(4);
+    assert_eq!(FormattedParameterStruct { label: String::from("x4") }, test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+enum VirtualParameterEnum {
+    #[ctor(sized(size: usize))]
+    Variant(#[ctor(expr(vec![0; size]))] Vec<u8>),
+}
+
+#[test]
+fn test_enum_ctor_with_virtual_parameter() {
+    assert_eq!(VirtualParameterEnum::Variant(vec![0, 0]), VirtualParameterEnum::sized(2));
+}
@@ -0,0 +1,82 @@
+mod inner {
+    use derive_ctor::ctor;
+
+    #[derive(ctor, Debug, PartialEq)]
+    #[ctor(vis = inherit)]
+    pub(crate) struct Inherited {
+        pub(crate) value: u32,
+    }
+
+    #[derive(ctor, Debug, PartialEq)]
+    #[ctor(vis = pub(crate), new, other, pub(crate) explicit)]
+    pub struct BareNames {
+        pub value: u32,
+    }
+
+    #[derive(ctor, Debug, PartialEq)]
+    #[ctor(vis = pub, new, default)]
+    pub struct DefaultWithVisibility {
+        #[ctor(default)]
+        pub value: u32,
+    }
+
+    #[derive(ctor, Debug, PartialEq)]
+    #[ctor(vis = inherit)]
+    pub(crate) enum InheritedVariants {
+        #[ctor(vis = inherit, value)]
+        Value(u32),
+    }
+
+    #[derive(ctor, Debug, PartialEq)]
+    #[ctor(vis = inherit)]
+    pub enum EnumInheritVariants {
+        Value(u32),
+    }
+
+    #[derive(ctor, Debug, PartialEq)]
+    pub enum VariantVisibility {
+        #[ctor(vis = pub(crate))]
+        First(u32),
+        #[ctor(vis = pub(crate))]
+        Second,
+    }
+
+    #[derive(ctor, Debug, PartialEq)]
+    pub enum DefaultVariantWithVisibility {
+        #[ctor(vis = pub, default)]
+        Empty,
+    }
+}
+
+use inner::*;
+
+#[test]
+fn test_struct_inherited_visibility() {
+    assert_eq!(Inherited { value: 1 }, Inherited::new(1));
+}
+
+#[test]
+fn test_struct_default_visibility_of_bare_names() {
+    assert_eq!(BareNames { value: 1 }, BareNames::new(1));
+    assert_eq!(BareNames { value: 2 }, BareNames::other(2));
+    assert_eq!(BareNames { value: 3 }, BareNames::explicit(3));
+}
+
+#[test]
+fn test_enum_inherited_visibility() {
+    assert_eq!(InheritedVariants::Value(1), InheritedVariants::value(1));
+    assert_eq!(EnumInheritVariants::Value(1), EnumInheritVariants::value(1));
+}
+
+#[test]
+fn test_variant_visibility_keeps_variant_ctor() {
+    assert_eq!(VariantVisibility::First(1), VariantVisibility::first(1));
+    assert_eq!(VariantVisibility::Second, VariantVisibility::second());
+}
+
+#[test]
+fn test_default_ignores_visibility() {
+    assert_eq!(DefaultWithVisibility { value: 0 }, DefaultWithVisibility::new());
+    assert_eq!(DefaultWithVisibility { value: 0 }, Default::default());
+    assert_eq!(DefaultVariantWithVisibility::Empty, Default::default());
+}
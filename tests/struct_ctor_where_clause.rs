@@ -0,0 +1,63 @@
+use derive_ctor::ctor;
+
+#[derive(Debug, PartialEq)]
+struct NotClone;
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, pub duplicated where T: Clone, other)]
+struct WhereClauseStruct<T> {
+    #[ctor(expr(value.clone()) = duplicated)]
+    copy: T,
+    value: T,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, pub with_default where T: Default + Clone, U: Default)]
+struct MultiPredicateStruct<T, U> {
+    #[ctor(default = with_default)]
+    first: T,
+    #[ctor(default = with_default)]
+    second: U,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, pub from<V: Into<u32>>(input: V))]
+struct MethodGenericStruct {
+    #[ctor(expr(input.into()) = from)]
+    value: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, default where T: Default)]
+struct DefaultWhereStruct<T> {
+    #[ctor(default = default)]
+    value: T,
+}
+
+#[test]
+fn test_method_where_clause() {
+    let test = WhereClauseStruct::new(NotClone, NotClone);
+    assert_eq!(WhereClauseStruct { copy: NotClone, value: NotClone }, test);
+
+    let test = WhereClauseStruct::duplicated(5);
+    assert_eq!(WhereClauseStruct { copy: 5, value: 5 }, test);
+
+    let test = WhereClauseStruct::other(1, 2);
+    assert_eq!(WhereClauseStruct { copy: 1, value: 2 }, test);
+
+    let test: MultiPredicateStruct<u8, bool> = MultiPredicateStruct::with_default();
+    assert_eq!(MultiPredicateStruct { first: 0, second: false }, test);
+}
+
+#[test]
+fn test_method_generics() {
+    let test = MethodGenericStruct::from(5u8);
+    assert_eq!(MethodGenericStruct { value: 5 }, test);
+}
+
+#[test]
+fn test_default_where_clause() {
+    let test: DefaultWhereStruct<u32> = Default::default();
+    assert_eq!(DefaultWhereStruct { value: 0 }, test);
+    assert_eq!(DefaultWhereStruct { value: NotClone }, DefaultWhereStruct::new(NotClone));
+}
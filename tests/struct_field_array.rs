@@ -0,0 +1,125 @@
+use derive_ctor::ctor;
+use derive_ctor_runtime::ArrayLengthError;
+use std::num::TryFromIntError;
+
+#[derive(ctor, Debug, PartialEq)]
+struct RepeatStruct {
+    #[ctor(repeat(String::from("Foo")))]
+    names: [String; 3],
+    #[ctor(repeat(fill))]
+    values: [u8; 2],
+    fill: u8,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct FromFnStruct {
+    #[ctor(from_fn(|i| i * offset))]
+    values: [usize; 4],
+    offset: usize,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct ArrayIterStruct {
+    #[ctor(iter(u32))]
+    values: [u32; 3],
+}
+
+#[derive(Debug, PartialEq)]
+enum ArrayError {
+    Length(ArrayLengthError),
+    Conversion,
+}
+
+impl From<ArrayLengthError> for ArrayError {
+    fn from(error: ArrayLengthError) -> Self {
+        ArrayError::Length(error)
+    }
+}
+
+impl From<TryFromIntError> for ArrayError {
+    fn from(_: TryFromIntError) -> Self {
+        ArrayError::Conversion
+    }
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(error = ArrayError))]
+struct FallibleArrayIterStruct {
+    #[ctor(iter(u32))]
+    values: [u32; 3],
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(error = ArrayError))]
+struct TryIntoArrayIterStruct {
+    #[ctor(iter(try_into u8))]
+    values: [u8; 2],
+}
+
+#[test]
+fn test_array_repeat() {
+    let test = RepeatStruct::new(7);
+    assert_eq!(
+        RepeatStruct {
+            names: [String::from("Foo"), String::from("Foo"), String::from("Foo")],
+            values: [7, 7],
+            fill: 7
+        },
+        test
+    );
+}
+
+#[test]
+fn test_array_from_fn() {
+    let test = FromFnStruct::new(2);
+    assert_eq!(FromFnStruct { values: [0, 2, 4, 6], offset: 2 }, test);
+}
+
+#[test]
+fn test_array_iter() {
+    let test = ArrayIterStruct::new(vec![1, 2, 3]);
+    assert_eq!(ArrayIterStruct { values: [1, 2, 3] }, test);
+}
+
+#[test]
+#[should_panic(expected = "fewer items")]
+fn test_array_iter_too_few() {
+    ArrayIterStruct::new(vec![1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "more items")]
+fn test_array_iter_too_many() {
+    ArrayIterStruct::new(0..4);
+}
+
+#[test]
+fn test_fallible_array_iter() {
+    assert_eq!(Ok(FallibleArrayIterStruct { values: [1, 2, 3] }), FallibleArrayIterStruct::new(vec![1, 2, 3]));
+}
+
+#[test]
+fn test_fallible_array_iter_too_few() {
+    assert_eq!(
+        Err(ArrayError::Length(ArrayLengthError::TooFew { expected: 3, found: 2 })),
+        FallibleArrayIterStruct::new(vec![1, 2])
+    );
+}
+
+#[test]
+fn test_fallible_array_iter_too_many() {
+    assert_eq!(
+        Err(ArrayError::Length(ArrayLengthError::TooMany { expected: 3 })),
+        FallibleArrayIterStruct::new(0..4)
+    );
+}
+
+#[test]
+fn test_try_into_array_iter() {
+    assert_eq!(Ok(TryIntoArrayIterStruct { values: [1, 2] }), TryIntoArrayIterStruct::new(vec![1u32, 2]));
+    assert_eq!(Err(ArrayError::Conversion), TryIntoArrayIterStruct::new(vec![1u32, 300]));
+    assert_eq!(
+        Err(ArrayError::Length(ArrayLengthError::TooFew { expected: 2, found: 1 })),
+        TryIntoArrayIterStruct::new(vec![1u32])
+    );
+}
@@ -92,6 +92,7 @@ struct ComplexSelfRefExpr {
     n2: u32,
 }
 
+#[allow(clippy::box_collection)]
 #[derive(ctor, Debug, PartialEq)]
 struct ChangeInputTypeExpr {
     #[ctor(expr(String -> Box::new(value)))]
@@ -120,3 +121,41 @@ fn test_changed_input_type() {
         test
     )
 }
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct MultiInputExpr {
+    #[ctor(expr((x: f32, y: f32) -> Point { x, y }))]
+    position: Point,
+    #[ctor(expr((width: u32) -> width * 2))]
+    w: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct GenericInputTypeExpr {
+    #[ctor(expr(Vec<u8> -> value.len()))]
+    value: usize,
+}
+
+#[test]
+fn test_multi_input_expr() {
+    let test = MultiInputExpr::new(1.0, 2.0, 5);
+    assert_eq!(
+        MultiInputExpr {
+            position: Point { x: 1.0, y: 2.0 },
+            w: 10
+        },
+        test
+    )
+}
+
+#[test]
+fn test_changed_generic_input_type() {
+    let test = GenericInputTypeExpr::new(vec![1, 2, 3]);
+    assert_eq!(GenericInputTypeExpr { value: 3 }, test)
+}
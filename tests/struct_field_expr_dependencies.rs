@@ -0,0 +1,88 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+struct DependentExpr {
+    #[ctor(expr(items.len()))]
+    len: usize,
+    #[ctor(iter(u8))]
+    items: Vec<u8>,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct ChainedExpr {
+    #[ctor(expr(middle * 2))]
+    last: u32,
+    #[ctor(expr(first + 1))]
+    middle: u32,
+    #[ctor(expr!(first * 10))]
+    first: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct RawParameterExpr {
+    #[ctor(expr(raw!(value).len()))]
+    original_len: usize,
+    #[ctor(expr!(value.trim().to_string()))]
+    value: String,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct FormattedExpr {
+    #[ctor(expr(format!("{name}!")))]
+    greeting: String,
+    #[ctor(expr!(name.to_uppercase()))]
+    name: String,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct LocalBindingExpr {
+    #[ctor(expr({ let total = 5; total * 2 }))]
+    double: u32,
+    #[ctor(expr(double + 1))]
+    total: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct ClosureParameterExpr {
+    #[ctor(expr(items.iter().map(|total| total * 2).sum()))]
+    doubled: u32,
+    #[ctor(expr(doubled + 1))]
+    total: u32,
+    #[ctor(iter(u32))]
+    items: Vec<u32>,
+}
+
+#[test]
+fn test_expr_referencing_later_field() {
+    let test = DependentExpr::new([1, 2, 3]);
+    assert_eq!(DependentExpr { len: 3, items: vec![1, 2, 3] }, test);
+}
+
+#[test]
+fn test_expr_chained_dependencies() {
+    let test = ChainedExpr::new(2);
+    assert_eq!(ChainedExpr { last: 42, middle: 21, first: 20 }, test);
+}
+
+#[test]
+fn test_expr_referencing_raw_parameter() {
+    let test = RawParameterExpr::new(String::from("  abc  "));
+    assert_eq!(RawParameterExpr { original_len: 7, value: String::from("abc") }, test);
+}
+
+#[test]
+fn test_expr_referencing_field_in_format_string() {
+    let test = FormattedExpr::new(String::from("abc"));
+    assert_eq!(FormattedExpr { greeting: String::from("ABC!"), name: String::from("ABC") }, test);
+}
+
+#[test]
+fn test_expr_local_binding_is_not_a_dependency() {
+    assert_eq!(LocalBindingExpr { double: 10, total: 11 }, LocalBindingExpr::new());
+}
+
+#[test]
+fn test_expr_closure_parameter_is_not_a_dependency() {
+    let test = ClosureParameterExpr::new([1, 2]);
+    assert_eq!(ClosureParameterExpr { doubled: 6, total: 7, items: vec![1, 2] }, test);
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet, VecDeque};
 
 use derive_ctor::ctor;
 
@@ -24,3 +24,39 @@ fn test_struct_with_field_iter() {
         test
     );
 }
+
+#[derive(ctor, Debug, PartialEq)]
+struct StructInferredIter {
+    #[ctor(iter)]
+    set: HashSet<usize>,
+    #[ctor(iter)]
+    deque: VecDeque<u8>,
+    #[ctor(iter)]
+    map: BTreeMap<String, u32>,
+    #[ctor(iter)]
+    boxed: Box<[i16]>,
+    #[ctor(iter)]
+    string: String,
+}
+
+#[test]
+fn test_struct_with_inferred_field_iter() {
+    let test = StructInferredIter::new(
+        vec![2, 2],
+        [1, 2],
+        [(String::from("Foo"), 1)],
+        vec![-1],
+        ['a', 'b'],
+    );
+
+    assert_eq!(
+        StructInferredIter {
+            set: HashSet::from([2]),
+            deque: VecDeque::from([1, 2]),
+            map: BTreeMap::from([(String::from("Foo"), 1)]),
+            boxed: Box::new([-1]),
+            string: String::from("ab")
+        },
+        test
+    );
+}
@@ -0,0 +1,88 @@
+use std::num::TryFromIntError;
+
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+struct StructIterInto {
+    #[ctor(iter(into String))]
+    names: Vec<String>,
+    #[ctor(iter(into))]
+    values: [u64; 2],
+}
+
+#[test]
+fn test_struct_with_field_iter_into() {
+    let test = StructIterInto::new(["a", "b"], [1u8, 2u8]);
+    assert_eq!(
+        StructIterInto {
+            names: vec![String::from("a"), String::from("b")],
+            values: [1, 2]
+        },
+        test
+    );
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct StructIterTryInto {
+    #[ctor(iter(try_into u8))]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn test_struct_with_field_iter_try_into() {
+    assert_eq!(Ok(StructIterTryInto { bytes: vec![1, 255] }), StructIterTryInto::new([1u32, 255u32]));
+    assert!(StructIterTryInto::new([1i32, 256i32]).is_err());
+}
+
+#[derive(Debug, PartialEq)]
+struct ConversionError;
+
+impl From<TryFromIntError> for ConversionError {
+    fn from(_: TryFromIntError) -> Self {
+        ConversionError
+    }
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(error = ConversionError))]
+struct StructIterTryIntoMultiple {
+    #[ctor(iter(try_into u8))]
+    bytes: Vec<u8>,
+    #[ctor(iter(try_into))]
+    shorts: Vec<i16>,
+}
+
+#[test]
+fn test_struct_with_multiple_field_iter_try_into() {
+    assert_eq!(
+        Ok(StructIterTryIntoMultiple { bytes: vec![1], shorts: vec![-1] }),
+        StructIterTryIntoMultiple::new([1u16], [-1i64])
+    );
+    assert_eq!(Err(ConversionError), StructIterTryIntoMultiple::new([1u16], [i64::MAX]));
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(generics = named))]
+struct StructIterTryIntoNamed {
+    #[ctor(iter(try_into u8))]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn test_struct_with_field_iter_try_into_named_generics() {
+    let test = StructIterTryIntoNamed::new::<u32, [u32; 1]>([7]);
+    assert_eq!(Ok(StructIterTryIntoNamed { bytes: vec![7] }), test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new(generics = named))]
+struct StructIterIntoNamed {
+    #[ctor(iter(into String))]
+    names: Vec<String>,
+}
+
+#[test]
+fn test_struct_with_field_iter_into_named_generics() {
+    let test = StructIterIntoNamed::new::<&str, [&str; 2]>(["Foo", "Bar"]);
+    assert_eq!(StructIterIntoNamed { names: vec![String::from("Foo"), String::from("Bar")] }, test);
+}
@@ -0,0 +1,77 @@
+use derive_ctor::ctor;
+
+#[derive(Debug, Default, PartialEq)]
+struct Unit;
+
+#[derive(Debug, Default, PartialEq)]
+struct Tag<T>(std::marker::PhantomData<T>);
+
+#[derive(ctor, Debug, PartialEq)]
+struct MarkerField {
+    value: u32,
+    #[ctor(marker)]
+    unit: Unit,
+}
+
+#[test]
+fn test_marker_field_excluded_as_parameter() {
+    let test = MarkerField::new(4);
+    assert_eq!(MarkerField { value: 4, unit: Unit }, test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(markers(Unit, Tag<_>))]
+struct DeclaredMarkers {
+    value: u32,
+    unit: Unit,
+    tag: Tag<String>,
+}
+
+#[test]
+fn test_declared_markers_excluded_as_parameters() {
+    let test = DeclaredMarkers::new(4);
+    assert_eq!(DeclaredMarkers { value: 4, unit: Unit, tag: Tag::default() }, test);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, markers(Unit), with_unit(into))]
+struct DeclaredMarkersWithDefinitions {
+    value: u32,
+    unit: Unit,
+}
+
+#[test]
+fn test_declared_markers_alongside_definitions() {
+    let test1 = DeclaredMarkersWithDefinitions::new(4);
+    let test2 = DeclaredMarkersWithDefinitions::with_unit(5u8);
+    assert_eq!(DeclaredMarkersWithDefinitions { value: 4, unit: Unit }, test1);
+    assert_eq!(DeclaredMarkersWithDefinitions { value: 5, unit: Unit }, test2);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(markers(Tag<_>))]
+enum EnumMarkers {
+    Tagged(u32, Tag<u8>),
+    #[ctor(pub other)]
+    Other { tag: Tag<bool> },
+}
+
+#[test]
+fn test_enum_markers_excluded_as_parameters() {
+    assert_eq!(EnumMarkers::Tagged(4, Tag::default()), EnumMarkers::tagged(4));
+    assert_eq!(EnumMarkers::Other { tag: Tag::default() }, EnumMarkers::other());
+}
+
+#[derive(ctor, Debug, PartialEq)]
+enum VariantMarkers {
+    #[ctor(markers(Unit))]
+    First(u32, Unit),
+    #[ctor(markers(Tag<_>))]
+    Second(Tag<u8>),
+}
+
+#[test]
+fn test_variant_markers_keep_variant_ctor() {
+    assert_eq!(VariantMarkers::First(4, Unit), VariantMarkers::first(4));
+    assert_eq!(VariantMarkers::Second(Tag::default()), VariantMarkers::second());
+}
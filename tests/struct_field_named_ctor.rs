@@ -0,0 +1,51 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new, with_defaults, empty)]
+struct NamedApplicationStruct {
+    #[ctor(default = [with_defaults, empty])]
+    arg1: u32,
+    #[ctor(expr(5) = empty)]
+    arg2: u32,
+}
+
+#[test]
+fn test_struct_with_named_applications() {
+    let new = NamedApplicationStruct::new(1, 2);
+    assert_eq!(NamedApplicationStruct { arg1: 1, arg2: 2 }, new);
+
+    let with_defaults = NamedApplicationStruct::with_defaults(3);
+    assert_eq!(NamedApplicationStruct { arg1: 0, arg2: 3 }, with_defaults);
+
+    let empty = NamedApplicationStruct::empty();
+    assert_eq!(NamedApplicationStruct { arg1: 0, arg2: 5 }, empty);
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new, other)]
+struct MixedApplicationStruct {
+    #[ctor(default = [0, other])]
+    arg1: u32,
+    arg2: u32,
+}
+
+#[test]
+fn test_struct_with_mixed_applications() {
+    assert_eq!(MixedApplicationStruct { arg1: 0, arg2: 7 }, MixedApplicationStruct::new(7));
+    assert_eq!(MixedApplicationStruct { arg1: 0, arg2: 8 }, MixedApplicationStruct::other(8));
+}
+
+#[derive(ctor, Debug, PartialEq)]
+enum NamedApplicationEnum {
+    #[ctor(new, empty)]
+    Variant {
+        #[ctor(default = empty)]
+        value: u32,
+    },
+}
+
+#[test]
+fn test_enum_with_named_applications() {
+    assert_eq!(NamedApplicationEnum::Variant { value: 4 }, NamedApplicationEnum::new(4));
+    assert_eq!(NamedApplicationEnum::Variant { value: 0 }, NamedApplicationEnum::empty());
+}
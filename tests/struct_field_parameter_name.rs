@@ -0,0 +1,52 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+struct RenamedTupleStruct(#[ctor(name = width)] u32, #[ctor(into, name = label)] String);
+
+#[derive(ctor, Debug, PartialEq)]
+struct RenamedStruct {
+    #[ctor(name = width)]
+    w: u32,
+    #[ctor(expr(w * 2))]
+    double: u32,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct OrderedStruct {
+    a: u32,
+    b: String,
+    #[ctor(order = 0)]
+    c: bool,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(new, reversed(order(c, b)))]
+struct DefinitionOrderedStruct {
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+#[test]
+fn test_renamed_parameters() {
+    let test = RenamedTupleStruct::new(5, "Foo");
+    assert_eq!(RenamedTupleStruct(5, String::from("Foo")), test);
+
+    let test = RenamedStruct::new(3);
+    assert_eq!(RenamedStruct { w: 3, double: 6 }, test);
+}
+
+#[test]
+fn test_field_ordered_parameters() {
+    let test = OrderedStruct::new(true, 1, String::from("Bar"));
+    assert_eq!(OrderedStruct { a: 1, b: String::from("Bar"), c: true }, test);
+}
+
+#[test]
+fn test_definition_ordered_parameters() {
+    let test = DefinitionOrderedStruct::new(1, 2, 3);
+    assert_eq!(DefinitionOrderedStruct { a: 1, b: 2, c: 3 }, test);
+
+    let test = DefinitionOrderedStruct::reversed(3, 2, 1);
+    assert_eq!(DefinitionOrderedStruct { a: 1, b: 2, c: 3 }, test);
+}
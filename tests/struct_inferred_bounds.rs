@@ -0,0 +1,43 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+struct InferredDefault<T> {
+    #[ctor(default)]
+    value: T,
+    items: Vec<u8>,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+struct InferredClone<T> {
+    #[ctor(cloned)]
+    value: T,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(default(all))]
+struct InferredDefaultAll<T, U> {
+    first: T,
+    second: Option<U>,
+}
+
+#[derive(Debug, PartialEq)]
+struct NoDefault;
+
+#[test]
+fn test_inferred_default_bound() {
+    let test: InferredDefault<u32> = InferredDefault::new(vec![1]);
+    assert_eq!(InferredDefault { value: 0, items: vec![1] }, test);
+}
+
+#[test]
+fn test_inferred_clone_bound() {
+    let value = String::from("Foo");
+    let test = InferredClone::new(&value);
+    assert_eq!(InferredClone { value }, test);
+}
+
+#[test]
+fn test_inferred_default_impl_bound() {
+    let test: InferredDefaultAll<u32, NoDefault> = Default::default();
+    assert_eq!(InferredDefaultAll { first: 0, second: None }, test);
+}
@@ -18,3 +18,25 @@ fn test_phantom_data_auto_excluded_as_parameter() {
         pd
     )
 }
+
+#[derive(ctor, Debug, PartialEq)]
+struct HasStdMarkers {
+    value: u32,
+    _pinned: std::marker::PhantomPinned,
+    _unit: (),
+    phantoms: Vec<PhantomData<u32>>,
+}
+
+#[test]
+fn test_std_markers_auto_excluded_as_parameters() {
+    let markers = HasStdMarkers::new(4, vec![PhantomData]);
+    assert_eq!(
+        HasStdMarkers {
+            value: 4,
+            _pinned: std::marker::PhantomPinned,
+            _unit: (),
+            phantoms: vec![PhantomData]
+        },
+        markers
+    )
+}
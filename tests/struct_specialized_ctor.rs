@@ -0,0 +1,81 @@
+use derive_ctor::ctor;
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, pub bytes for <u8, 4>, pub words for <u16, 2>)]
+struct Buffer<T, const N: usize> {
+    #[ctor(expr([T::default(); N]) = [bytes, words])]
+    data: [T; N],
+    #[ctor(default = [bytes, words])]
+    len: usize,
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, pub borrowed for <'static, str>)]
+struct Reference<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+struct Sizes;
+
+impl Sizes {
+    const N: usize = 3;
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub new, pub pair for <2>)]
+struct Counts<const N: usize> {
+    #[ctor(expr(Sizes::N * N) = pair)]
+    total: usize,
+    #[ctor(expr([0; N]) = pair)]
+    data: [u8; N],
+}
+
+#[derive(ctor, Debug, PartialEq)]
+#[ctor(pub full for <u8>, pub full for <u16>)]
+struct Filled<T> {
+    #[ctor(expr(T::MAX))]
+    value: T,
+}
+
+#[derive(ctor)]
+#[ctor(pub new, pub empty for <std::vec::IntoIter<u8>>)]
+struct FirstItem<T: Iterator> {
+    #[ctor(default = empty)]
+    first: Option<T::Item>,
+}
+
+#[test]
+fn test_specialized_ctors() {
+    let generic: Buffer<bool, 1> = Buffer::new([true], 1);
+    assert_eq!(Buffer { data: [true], len: 1 }, generic);
+
+    let bytes = Buffer::bytes();
+    assert_eq!(Buffer { data: [0u8; 4], len: 0 }, bytes);
+
+    let words = Buffer::words();
+    assert_eq!(Buffer { data: [0u16; 2], len: 0 }, words);
+}
+
+#[test]
+fn test_specialized_lifetime_ctor() {
+    let reference = Reference::borrowed("Foo");
+    assert_eq!(Reference { value: "Foo" }, reference);
+}
+
+#[test]
+fn test_specialized_ctor_only_substitutes_generic_parameters() {
+    let pair = Counts::pair();
+    assert_eq!(Counts { total: 6, data: [0, 0] }, pair);
+}
+
+#[test]
+fn test_specialized_ctors_sharing_a_name() {
+    assert_eq!(Filled { value: u8::MAX }, Filled::<u8>::full());
+    assert_eq!(Filled { value: u16::MAX }, Filled::<u16>::full());
+}
+
+#[test]
+fn test_specialized_ctor_with_associated_type() {
+    assert_eq!(None, FirstItem::empty().first);
+    assert_eq!(Some(1u8), FirstItem::<std::vec::IntoIter<u8>>::new(Some(1)).first);
+}